@@ -1,14 +1,36 @@
 use bevy::prelude::*;
 
+use crate::animation::{
+    sprite_animation::{SpriteAnimation, SpriteAnimationLoader, advance_animations},
+    tween::{TweenFinished, advance_tweens},
+};
+
 pub mod sprite_animation;
+pub mod tween;
+
+pub struct SpriteAnimationPlugin;
+
+impl Plugin for SpriteAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<SpriteAnimation>()
+            .init_asset_loader::<SpriteAnimationLoader>()
+            .add_event::<AnimationEvent>()
+            .add_event::<TweenFinished>()
+            .add_systems(Update, (advance_animations, advance_tweens));
+    }
+}
 
-#[derive(Event)]
+#[derive(Event, Clone, Debug)]
 pub struct AnimationEvent {
     pub entity: Entity,
+    pub clip: String,
     pub kind: AnimationEventKind,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AnimationEventKind {
+    /// A `Once` clip reached its last frame, or a looping clip completed a cycle.
     Finished,
+    /// A frame marked `interaction: true` was entered.
     Interaction,
 }
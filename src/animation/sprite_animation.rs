@@ -1,45 +1,315 @@
-use bevy::prelude::*;
+use std::collections::HashMap;
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use serde::Deserialize;
 
 use crate::animation::{AnimationEvent, AnimationEventKind};
 
-#[derive(Component, Clone)]
-pub struct SpriteAnimState {
-    pub start_index: usize,
-    pub end_index: usize,
-    pub timer: Timer,
+/// Frames shorter than this are clamped so a zero duration cannot stall the loop.
+const MIN_FRAME_SECONDS: f32 = 0.001;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum PlaybackMode {
+    #[default]
+    Loop,
+    /// Plays through once and holds the last frame.
+    Once,
+    /// Plays forwards then backwards, forever.
+    PingPong,
 }
 
-impl Default for SpriteAnimState {
-    fn default() -> Self {
+#[derive(Clone, Debug, Deserialize)]
+pub struct AnimationFrame {
+    /// Index into the sprite's texture atlas.
+    pub index: usize,
+    pub seconds: f32,
+    /// Sends `AnimationEventKind::Interaction` whenever the frame is entered.
+    #[serde(default)]
+    pub interaction: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct AnimationClip2d {
+    pub frames: Vec<AnimationFrame>,
+    #[serde(default)]
+    pub mode: PlaybackMode,
+}
+
+/// Named clips for one texture atlas, shared by every sprite using it. Loaded
+/// from `.anim.ron` files under `assets/animations`.
+#[derive(Asset, TypePath, Clone, Debug, Default, Deserialize)]
+pub struct SpriteAnimation {
+    pub clips: HashMap<String, AnimationClip2d>,
+}
+
+impl SpriteAnimation {
+    pub fn clip(&self, name: &str) -> Option<&AnimationClip2d> {
+        self.clips.get(name)
+    }
+}
+
+#[derive(Default)]
+pub struct SpriteAnimationLoader;
+
+impl AssetLoader for SpriteAnimationLoader {
+    type Asset = SpriteAnimation;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<SpriteAnimation, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["anim.ron"]
+    }
+}
+
+/// Plays clips from a `SpriteAnimation` on the entity's `Sprite` atlas.
+#[derive(Component, Clone, Debug)]
+#[require(Sprite)]
+pub struct AnimationPlayer2d {
+    pub animation: Handle<SpriteAnimation>,
+    /// Playback rate multiplier.
+    pub speed: f32,
+    clip: String,
+    frame: usize,
+    elapsed: f32,
+    reversing: bool,
+    finished: bool,
+    /// The current frame has not been shown (and its event not sent) yet.
+    entering: bool,
+}
+
+impl AnimationPlayer2d {
+    pub fn new(animation: Handle<SpriteAnimation>, clip: &str) -> Self {
         Self {
-            start_index: 0,
-            end_index: Default::default(),
-            timer: Timer::from_seconds(2.0, TimerMode::Repeating),
+            animation,
+            speed: 1.0,
+            clip: clip.to_string(),
+            frame: 0,
+            elapsed: 0.0,
+            reversing: false,
+            finished: false,
+            entering: true,
+        }
+    }
+
+    /// Switches to `clip`, restarting it unless it is already playing.
+    pub fn play(&mut self, clip: &str) {
+        if self.clip == clip && !self.finished {
+            return;
+        }
+        self.restart(clip);
+    }
+
+    /// Starts `clip` from its first frame even if it is already playing.
+    pub fn restart(&mut self, clip: &str) {
+        self.clip = clip.to_string();
+        self.frame = 0;
+        self.elapsed = 0.0;
+        self.reversing = false;
+        self.finished = false;
+        self.entering = true;
+    }
+
+    pub fn clip(&self) -> &str {
+        &self.clip
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Steps to the next frame, returning `true` when a cycle or a `Once` clip ended.
+    fn advance(&mut self, clip: &AnimationClip2d) -> bool {
+        let last = clip.frames.len() - 1;
+        match clip.mode {
+            PlaybackMode::Loop => {
+                self.frame = if self.frame >= last {
+                    0
+                } else {
+                    self.frame + 1
+                };
+                self.frame == 0
+            }
+            PlaybackMode::Once => {
+                if self.frame >= last {
+                    self.finished = true;
+                    true
+                } else {
+                    self.frame += 1;
+                    false
+                }
+            }
+            PlaybackMode::PingPong => {
+                if last == 0 {
+                    return true;
+                }
+                if self.reversing {
+                    self.frame -= 1;
+                    if self.frame == 0 {
+                        self.reversing = false;
+                        return true;
+                    }
+                } else {
+                    self.frame += 1;
+                    if self.frame == last {
+                        self.reversing = true;
+                    }
+                }
+                false
+            }
         }
     }
 }
 
-pub fn animate_spite(
+pub fn advance_animations(
     time: Res<Time>,
-    mut query: Query<(Entity, &mut Sprite, &mut SpriteAnimState)>,
-    mut event_writer_anim: EventWriter<AnimationEvent>,
+    animations: Res<Assets<SpriteAnimation>>,
+    mut query: Query<(Entity, &mut AnimationPlayer2d, &mut Sprite)>,
+    mut animation_events: EventWriter<AnimationEvent>,
 ) {
-    for (entity, mut sprite, mut anim_state) in query.iter_mut() {
-        anim_state.timer.tick(time.delta());
-        if anim_state.timer.finished() {
-            if let Some(texture_atlas) = &mut sprite.texture_atlas {
-                // info!(
-                //     "Current index: {}, Start index: {}, End index: {}",
-                //     texture_atlas.index, anim_state.start_index, anim_state.end_index
-                // );
-                texture_atlas.index += 1;
-                if texture_atlas.index > anim_state.end_index {
-                    texture_atlas.index = anim_state.start_index;
-                    event_writer_anim.write(AnimationEvent {
-                        kind: AnimationEventKind::Finished,
-                        entity,
-                    });
+    for (entity, mut player, mut sprite) in query.iter_mut() {
+        let Some(clip) = animations
+            .get(&player.animation)
+            .and_then(|animation| animation.clip(&player.clip))
+            .filter(|clip| !clip.frames.is_empty())
+        else {
+            continue;
+        };
+        let mut send = |clip: &str, kind: AnimationEventKind| {
+            animation_events.write(AnimationEvent {
+                entity,
+                clip: clip.to_string(),
+                kind,
+            });
+        };
+
+        if player.entering {
+            player.entering = false;
+            player.frame = player.frame.min(clip.frames.len() - 1);
+            if clip.frames[player.frame].interaction {
+                send(&player.clip, AnimationEventKind::Interaction);
+            }
+        } else if !player.finished {
+            // Several frames may pass in one update when frames are short.
+            player.elapsed += time.delta_secs() * player.speed;
+            loop {
+                let seconds = clip.frames[player.frame].seconds.max(MIN_FRAME_SECONDS);
+                if player.elapsed < seconds {
+                    break;
+                }
+                player.elapsed -= seconds;
+                if player.advance(clip) {
+                    send(&player.clip, AnimationEventKind::Finished);
                 }
+                if player.finished {
+                    break;
+                }
+                if clip.frames[player.frame].interaction {
+                    send(&player.clip, AnimationEventKind::Interaction);
+                }
+            }
+        }
+
+        if let Some(atlas) = &mut sprite.texture_atlas {
+            let index = clip.frames[player.frame].index;
+            if atlas.index != index {
+                atlas.index = index;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clip(indices: &[usize], mode: PlaybackMode) -> AnimationClip2d {
+        AnimationClip2d {
+            frames: indices
+                .iter()
+                .map(|&index| AnimationFrame {
+                    index,
+                    seconds: 0.1,
+                    interaction: false,
+                })
+                .collect(),
+            mode,
+        }
+    }
+
+    fn step(player: &mut AnimationPlayer2d, clip: &AnimationClip2d, steps: usize) -> Vec<bool> {
+        (0..steps).map(|_| player.advance(clip)).collect()
+    }
+
+    #[test]
+    fn loop_wraps_and_reports_each_cycle() {
+        let clip = clip(&[0, 1, 2], PlaybackMode::Loop);
+        let mut player = AnimationPlayer2d::new(Handle::default(), "idle");
+        assert_eq!(step(&mut player, &clip, 3), [false, false, true]);
+        assert_eq!(player.frame, 0);
+        assert!(!player.is_finished());
+    }
+
+    #[test]
+    fn once_holds_the_last_frame_and_finishes() {
+        let clip = clip(&[0, 1, 2], PlaybackMode::Once);
+        let mut player = AnimationPlayer2d::new(Handle::default(), "reach");
+        assert_eq!(step(&mut player, &clip, 2), [false, false]);
+        assert!(!player.is_finished());
+        assert!(player.advance(&clip));
+        assert!(player.is_finished());
+        assert_eq!(player.frame, 2);
+
+        player.play("reach");
+        assert!(!player.is_finished());
+        assert_eq!(player.frame, 0);
+    }
+
+    #[test]
+    fn ping_pong_reverses_at_both_ends() {
+        let clip = clip(&[0, 1, 2], PlaybackMode::PingPong);
+        let mut player = AnimationPlayer2d::new(Handle::default(), "idle");
+        let mut frames = Vec::new();
+        let mut cycles = 0;
+        for _ in 0..4 {
+            cycles += usize::from(player.advance(&clip));
+            frames.push(player.frame);
+        }
+        assert_eq!(frames, [1, 2, 1, 0]);
+        assert_eq!(cycles, 1);
+    }
+
+    #[test]
+    fn shipped_animation_files_parse() {
+        for (file, clips) in [
+            (
+                "bartender.anim.ron",
+                &["idle", "reach", "pour", "shake", "present", "wince"][..],
+            ),
+            ("icegel.anim.ron", &["idle"][..]),
+        ] {
+            let path = format!("{}/assets/animations/{file}", env!("CARGO_MANIFEST_DIR"));
+            let bytes = std::fs::read(&path).unwrap();
+            let animation: SpriteAnimation =
+                ron::de::from_bytes(&bytes).unwrap_or_else(|error| panic!("{file}: {error}"));
+            for name in clips {
+                let clip = animation
+                    .clip(name)
+                    .unwrap_or_else(|| panic!("{file} has no `{name}` clip"));
+                assert!(!clip.frames.is_empty(), "{file}: `{name}` has no frames");
             }
         }
     }
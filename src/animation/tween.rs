@@ -0,0 +1,260 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+/// Shapes progress through a tween step; `apply` maps 0..=1 onto 0..=1.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Ease {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicOut,
+    CubicInOut,
+    /// Overshoots slightly before settling, for things that pop into place.
+    BackOut,
+    /// Springs past the end a few times, for snap-backs.
+    ElasticOut,
+}
+
+impl Ease {
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Ease::Linear => t,
+            Ease::QuadIn => t * t,
+            Ease::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Ease::QuadInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Ease::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Ease::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Ease::BackOut => {
+                const OVERSHOOT: f32 = 1.70158;
+                let u = t - 1.0;
+                1.0 + (OVERSHOOT + 1.0) * u.powi(3) + OVERSHOOT * u.powi(2)
+            }
+            Ease::ElasticOut => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    2.0_f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+                }
+            }
+        }
+    }
+}
+
+/// What a tween step animates on its entity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TweenTarget {
+    Translation {
+        from: Vec3,
+        to: Vec3,
+    },
+    Scale {
+        from: Vec3,
+        to: Vec3,
+    },
+    SpriteColor {
+        from: Color,
+        to: Color,
+    },
+    /// `Node::left` and `Node::top`, in logical pixels.
+    NodePosition {
+        from: Vec2,
+        to: Vec2,
+    },
+    BackgroundColor {
+        from: Color,
+        to: Color,
+    },
+    /// Alpha of whichever of sprite, background, border and text colour the
+    /// entity has, keeping their hues.
+    Alpha {
+        from: f32,
+        to: f32,
+    },
+    /// Holds everything where it is, for pauses in a sequence.
+    Wait,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TweenStep {
+    pub target: TweenTarget,
+    pub seconds: f32,
+    pub ease: Ease,
+}
+
+/// A sequence of steps played one after another on the entity it sits on.
+/// Removed when done, after sending a `TweenFinished`.
+#[derive(Component, Clone, Debug)]
+pub struct Tween {
+    steps: Vec<TweenStep>,
+    current: usize,
+    elapsed: f32,
+    /// Names the tween in its `TweenFinished` event.
+    pub tag: Option<&'static str>,
+    pub despawn_on_finish: bool,
+}
+
+impl Tween {
+    pub fn new(target: TweenTarget, seconds: f32, ease: Ease) -> Self {
+        Self {
+            steps: vec![TweenStep {
+                target,
+                seconds,
+                ease,
+            }],
+            current: 0,
+            elapsed: 0.0,
+            tag: None,
+            despawn_on_finish: false,
+        }
+    }
+
+    pub fn then(mut self, target: TweenTarget, seconds: f32, ease: Ease) -> Self {
+        self.steps.push(TweenStep {
+            target,
+            seconds,
+            ease,
+        });
+        self
+    }
+
+    pub fn then_wait(self, seconds: f32) -> Self {
+        self.then(TweenTarget::Wait, seconds, Ease::Linear)
+    }
+
+    pub fn with_tag(mut self, tag: &'static str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    pub fn despawn_on_finish(mut self) -> Self {
+        self.despawn_on_finish = true;
+        self
+    }
+}
+
+#[derive(Event, Clone, Debug)]
+pub struct TweenFinished {
+    pub entity: Entity,
+    pub tag: Option<&'static str>,
+}
+
+type TweenedComponents = (
+    Entity,
+    &'static mut Tween,
+    Option<&'static mut Transform>,
+    Option<&'static mut Sprite>,
+    Option<&'static mut Node>,
+    Option<&'static mut BackgroundColor>,
+    Option<&'static mut BorderColor>,
+    Option<&'static mut TextColor>,
+);
+
+pub fn advance_tweens(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<TweenedComponents>,
+    mut finished: EventWriter<TweenFinished>,
+) {
+    for (
+        entity,
+        mut tween,
+        mut transform,
+        mut sprite,
+        mut node,
+        mut background,
+        mut border,
+        mut text,
+    ) in query.iter_mut()
+    {
+        tween.elapsed += time.delta_secs();
+        // Finish every step whose time is up this frame, then sample the current one.
+        loop {
+            let step = tween.steps[tween.current];
+            let done = tween.elapsed >= step.seconds;
+            let t = if step.seconds > 0.0 {
+                step.ease.apply(tween.elapsed / step.seconds)
+            } else {
+                1.0
+            };
+            let t = if done { 1.0 } else { t };
+            match step.target {
+                TweenTarget::Translation { from, to } => {
+                    if let Some(transform) = transform.as_mut() {
+                        transform.translation = from.lerp(to, t);
+                    }
+                }
+                TweenTarget::Scale { from, to } => {
+                    if let Some(transform) = transform.as_mut() {
+                        transform.scale = from.lerp(to, t);
+                    }
+                }
+                TweenTarget::SpriteColor { from, to } => {
+                    if let Some(sprite) = sprite.as_mut() {
+                        sprite.color = from.mix(&to, t);
+                    }
+                }
+                TweenTarget::NodePosition { from, to } => {
+                    if let Some(node) = node.as_mut() {
+                        let position = from.lerp(to, t);
+                        node.left = Val::Px(position.x);
+                        node.top = Val::Px(position.y);
+                    }
+                }
+                TweenTarget::BackgroundColor { from, to } => {
+                    if let Some(background) = background.as_mut() {
+                        background.0 = from.mix(&to, t);
+                    }
+                }
+                TweenTarget::Alpha { from, to } => {
+                    let alpha = from + (to - from) * t;
+                    if let Some(sprite) = sprite.as_mut() {
+                        sprite.color.set_alpha(alpha);
+                    }
+                    if let Some(background) = background.as_mut() {
+                        background.0.set_alpha(alpha);
+                    }
+                    if let Some(border) = border.as_mut() {
+                        border.0.set_alpha(alpha);
+                    }
+                    if let Some(text) = text.as_mut() {
+                        text.0.set_alpha(alpha);
+                    }
+                }
+                TweenTarget::Wait => {}
+            }
+            if !done {
+                break;
+            }
+            tween.elapsed -= step.seconds;
+            tween.current += 1;
+            if tween.current == tween.steps.len() {
+                finished.write(TweenFinished {
+                    entity,
+                    tag: tween.tag,
+                });
+                if tween.despawn_on_finish {
+                    commands.entity(entity).despawn();
+                } else {
+                    commands.entity(entity).remove::<Tween>();
+                }
+                break;
+            }
+        }
+    }
+}
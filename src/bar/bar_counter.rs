@@ -1,27 +1,76 @@
 use bevy::prelude::*;
 
 use crate::{
-    animation::sprite_animation::SpriteAnimState, bar::crafting::OnCraftingScreen,
-    engine::asset_loader::ImageAssets,
+    animation::{AnimationEvent, AnimationEventKind, sprite_animation::AnimationPlayer2d},
+    bar::{
+        crafting::{CraftingEvent, OnCraftingScreen},
+        reactions::Reaction,
+        technique::Technique,
+    },
+    engine::{
+        asset_loader::{AnimationAssets, ImageAssets},
+        layout::{AnchoredTo, Anchors, Surface, on_reference_area},
+    },
 };
 
+const IDLE_CLIP: &str = "idle";
+
+/// Bottle slots on the shelf art, top row left to right, then the bottom row.
+pub const SHELF_SLOTS: [&str; 6] = ["slot_0", "slot_1", "slot_2", "slot_3", "slot_4", "slot_5"];
+/// Icegel slots on the tray at the left of the counter.
+pub const ICEGEL_SLOTS: [&str; 3] = ["icegel_0", "icegel_1", "icegel_2"];
+
+const SHELF_ANCHORS: Anchors = Anchors(&[
+    ("slot_0", Vec2::new(-0.1888, 0.1074)),
+    ("slot_1", Vec2::new(-0.0586, 0.1074)),
+    ("slot_2", Vec2::new(0.0716, 0.1074)),
+    ("slot_3", Vec2::new(-0.1888, -0.0391)),
+    ("slot_4", Vec2::new(-0.0586, -0.0391)),
+    ("slot_5", Vec2::new(0.0716, -0.0391)),
+]);
+
+/// Shelf and counter art centres, as fractions of the reference area.
+const SHELF_POSITION: Vec2 = Vec2::new(-0.099, 0.046);
+const COUNTER_POSITION: Vec2 = Vec2::new(-0.104, -0.37);
+/// Both pieces of art are drawn at the same size.
+const SURFACE_ART_SIZE: Vec2 = Vec2::new(1536.0, 1024.0);
+
+const COUNTER_ANCHORS: Anchors = Anchors(&[
+    ("icegel_0", Vec2::new(-0.1628, 0.1953)),
+    ("icegel_1", Vec2::new(-0.1302, 0.1953)),
+    ("icegel_2", Vec2::new(-0.0977, 0.1953)),
+    ("glass", Vec2::new(0.2604, 0.1855)),
+    ("bartender", Vec2::new(0.3906, 0.2246)),
+]);
+
+/// The bartender behind the counter on the crafting screen.
+#[derive(Component, Default)]
+pub struct Bartender {
+    /// Clip to play once the current reaction finishes, before going back to idle.
+    pub queued: Option<&'static str>,
+}
+
 pub fn spawn_crafting_area(mut commands: Commands, image_assets: Res<ImageAssets>) {
     commands.spawn((
         Sprite {
             image: image_assets.bar_shelf.clone(),
-            custom_size: Some(Vec2::new(1536.0, 1024.0)),
+            custom_size: Some(SURFACE_ART_SIZE),
             ..default()
         },
-        Transform::from_xyz(-190.0, 50.0, -9.0),
+        on_reference_area(SHELF_POSITION, -9.0),
+        Surface::Shelf,
+        SHELF_ANCHORS,
         OnCraftingScreen,
     ));
     commands.spawn((
         Sprite {
             image: image_assets.bar_counter.clone(),
-            custom_size: Some(Vec2::new(1536.0, 1024.0)),
+            custom_size: Some(SURFACE_ART_SIZE),
             ..default()
         },
-        Transform::from_xyz(-200.0, -400.0, -8.0),
+        on_reference_area(COUNTER_POSITION, -8.0),
+        Surface::Counter,
+        COUNTER_ANCHORS,
         OnCraftingScreen,
     ));
 }
@@ -29,6 +78,7 @@ pub fn spawn_crafting_area(mut commands: Commands, image_assets: Res<ImageAssets
 pub fn spawn_bartender(
     mut commands: Commands,
     image_assets: Res<ImageAssets>,
+    animation_assets: Res<AnimationAssets>,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
 ) {
     let frame_size = UVec2::new(768, 1024);
@@ -49,12 +99,57 @@ pub fn spawn_bartender(
             custom_size: Some(Vec2::new(192., 256.)),
             ..default()
         },
-        Transform::from_translation(Vec3::new(400., -170., 1.)),
-        SpriteAnimState {
-            start_index: 0,
-            end_index: 1,
-            timer: Timer::from_seconds(1.0 / 12.0, TimerMode::Repeating),
-        },
+        Transform::from_xyz(0.0, 0.0, 1.0),
+        AnchoredTo::new(Surface::Counter, "bartender"),
+        AnimationPlayer2d::new(animation_assets.bartender.clone(), IDLE_CLIP),
+        Bartender::default(),
         OnCraftingScreen,
     ));
 }
+
+pub fn bartender_react(
+    mut crafting_events: EventReader<CraftingEvent>,
+    mut bartender_query: Query<(&mut Bartender, &mut AnimationPlayer2d)>,
+) {
+    // Only the latest action matters when several land in one frame.
+    let Some(event) = crafting_events.read().last() else {
+        return;
+    };
+    let (clip, queued) = match event {
+        CraftingEvent::Poured => ("reach", Some("pour")),
+        CraftingEvent::GlassFull => ("wince", None),
+        CraftingEvent::Reacted(Reaction::FoamOver) => ("pour", Some("wince")),
+        CraftingEvent::Reacted(_) => ("pour", None),
+        CraftingEvent::Preparing(Technique::Shake) => ("shake", None),
+        CraftingEvent::Preparing(Technique::Stir | Technique::Layer) => ("pour", None),
+        CraftingEvent::Preparing(Technique::Build) => ("reach", None),
+        CraftingEvent::Crafted => ("present", None),
+        CraftingEvent::Reset => ("reach", None),
+    };
+    for (mut bartender, mut player) in bartender_query.iter_mut() {
+        bartender.queued = queued;
+        player.restart(clip);
+    }
+}
+
+pub fn bartender_return_to_idle(
+    mut animation_events: EventReader<AnimationEvent>,
+    mut bartender_query: Query<(&mut Bartender, &mut AnimationPlayer2d)>,
+) {
+    for event in animation_events.read() {
+        if event.kind != AnimationEventKind::Finished || event.clip == IDLE_CLIP {
+            continue;
+        }
+        let Ok((mut bartender, mut player)) = bartender_query.get_mut(event.entity) else {
+            continue;
+        };
+        // A newer reaction may already have replaced the clip that finished.
+        if player.clip() != event.clip {
+            continue;
+        }
+        match bartender.queued.take() {
+            Some(next) => player.restart(next),
+            None => player.play(IDLE_CLIP),
+        }
+    }
+}
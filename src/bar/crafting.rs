@@ -1,19 +1,35 @@
 use bevy::prelude::*;
 
 use crate::{
-    animation::{AnimationEvent, sprite_animation::animate_spite},
     bar::{
-        bar_counter::{spawn_bartender, spawn_crafting_area},
+        bar_counter::{
+            bartender_react, bartender_return_to_idle, spawn_bartender, spawn_crafting_area,
+        },
         glass::spawn_glass,
+        reactions::Reaction,
+        technique::Technique,
+        temperature::drift_glass_temperature,
     },
-    engine::{GameState, audio_controller::play_crafting_bg},
+    engine::{GameState, audio_controller::play_crafting_bg, pause::PauseState},
     ingredients::spawn_ingredients,
-    ui::{crafting_ui::setup_glass_ui, ingredient_tooltip::despawn_glass_full_indicator},
+    ui::crafting_ui::setup_glass_ui,
 };
 
 #[derive(Component)]
 pub struct OnCraftingScreen;
 
+/// Something the player did at the counter, for anything that reacts to it.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CraftingEvent {
+    Poured,
+    GlassFull,
+    /// A pour set off a reaction; sent after its `Poured`.
+    Reacted(Reaction),
+    Preparing(Technique),
+    Crafted,
+    Reset,
+}
+
 #[derive(Component)]
 pub enum CraftButtons {
     Craft,
@@ -24,22 +40,29 @@ pub struct CraftingPlugin;
 
 impl Plugin for CraftingPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            OnEnter(GameState::Crafting),
-            (
-                spawn_ingredients,
-                (spawn_glass, setup_glass_ui).chain(),
-                spawn_bartender,
-                play_crafting_bg,
-                spawn_crafting_area,
-            ),
-        )
-        .add_systems(
-            Update,
-            (animate_spite, despawn_glass_full_indicator).run_if(in_state(GameState::Crafting)),
-        )
-        .add_systems(OnExit(GameState::Crafting), cleanup_crafting)
-        .add_event::<AnimationEvent>();
+        app.add_event::<CraftingEvent>()
+            .add_systems(
+                OnEnter(GameState::Crafting),
+                (
+                    spawn_ingredients,
+                    (spawn_glass, setup_glass_ui).chain(),
+                    spawn_bartender,
+                    play_crafting_bg,
+                    spawn_crafting_area,
+                ),
+            )
+            .add_systems(
+                Update,
+                (bartender_react, bartender_return_to_idle)
+                    .chain()
+                    .run_if(in_state(GameState::Crafting)),
+            )
+            .add_systems(
+                Update,
+                drift_glass_temperature
+                    .run_if(in_state(GameState::Crafting).and(in_state(PauseState::Running))),
+            )
+            .add_systems(OnExit(GameState::Crafting), cleanup_crafting);
     }
 }
 
@@ -2,10 +2,16 @@ use bevy::prelude::*;
 use std::collections::HashMap;
 
 use crate::{
-    bar::glass::{Glass, GlassShape},
-    customers::OnCustomerScreen,
+    animation::tween::{Ease, Tween, TweenTarget},
+    bar::{
+        glass::{Glass, GlassShape},
+        quality::{DrinkQuality, balance_score, fill_score},
+        reactions::Reaction,
+        technique::Technique,
+        temperature::{ROOM_TEMPERATURE, temperature_score},
+    },
     engine::{GameState, asset_loader::ImageAssets},
-    ingredients::{IngredientTaste, PrimaryEffect},
+    ingredients::{IngredientTaste, PrimaryEffect, SecondaryEffect},
     ui::crafting_ui::DrinkSprite,
 };
 
@@ -16,6 +22,14 @@ pub struct Drink {
     pub taste: DrinkTaste,
     pub effect: DrinkEffect,
     pub created_drink: CreatedDrink,
+    pub technique: Technique,
+    /// Execution quality of the technique, 0 to 1.
+    pub technique_quality: f32,
+    /// Degrees Celsius when it left the counter.
+    pub temperature: f32,
+    pub quality: DrinkQuality,
+    pub secondary_effects: Vec<SecondaryEffect>,
+    pub reactions: Vec<Reaction>,
 }
 
 #[derive(Debug)]
@@ -28,9 +42,11 @@ pub struct DrinkTaste {
 pub struct DrinkEffect {
     pub primary_effect: PrimaryEffect,
     pub secondary_effect: PrimaryEffect,
+    /// How strongly the primary effect lands, on the scale the Yarn scripts check.
+    pub strength: f32,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CreatedDrink {
     ZeroPhase,
     CryoDrop,
@@ -44,12 +60,70 @@ pub enum CreatedDrink {
     EventHorizon,
 }
 
+impl CreatedDrink {
+    /// Serving temperature in degrees Celsius the recipe is at its best.
+    pub fn ideal_temperature(&self) -> f32 {
+        match self {
+            CreatedDrink::CryoDrop | CreatedDrink::ZeroPhase => -4.0,
+            CreatedDrink::Cosmopolitan | CreatedDrink::StellarLumen => 2.0,
+            CreatedDrink::SynthCascade | CreatedDrink::BinaryBarrel => 6.0,
+            CreatedDrink::EchoBloom | CreatedDrink::BotanicalSurge => 10.0,
+            CreatedDrink::OldMemory | CreatedDrink::EventHorizon => ROOM_TEMPERATURE,
+        }
+    }
+
+    /// Share of each taste in a perfectly balanced serving.
+    pub fn ideal_tastes(&self) -> &'static [(IngredientTaste, f32)] {
+        match self {
+            CreatedDrink::ZeroPhase => &[
+                (IngredientTaste::Umami, 0.5),
+                (IngredientTaste::Bitter, 0.5),
+            ],
+            CreatedDrink::CryoDrop => {
+                &[(IngredientTaste::Sour, 0.5), (IngredientTaste::Sweet, 0.5)]
+            }
+            CreatedDrink::StellarLumen => &[
+                (IngredientTaste::Sweet, 0.6),
+                (IngredientTaste::Citrus, 0.4),
+            ],
+            CreatedDrink::Cosmopolitan => &[
+                (IngredientTaste::Citrus, 0.6),
+                (IngredientTaste::Sweet, 0.4),
+            ],
+            CreatedDrink::SynthCascade => {
+                &[(IngredientTaste::Spicy, 0.6), (IngredientTaste::Sour, 0.4)]
+            }
+            CreatedDrink::OldMemory => &[
+                (IngredientTaste::Bitter, 0.7),
+                (IngredientTaste::Umami, 0.3),
+            ],
+            CreatedDrink::EchoBloom => &[
+                (IngredientTaste::Umami, 0.6),
+                (IngredientTaste::Bitter, 0.4),
+            ],
+            CreatedDrink::BotanicalSurge => {
+                &[(IngredientTaste::Umami, 0.6), (IngredientTaste::Sweet, 0.4)]
+            }
+            CreatedDrink::BinaryBarrel => {
+                &[(IngredientTaste::Sour, 0.7), (IngredientTaste::Spicy, 0.3)]
+            }
+            CreatedDrink::EventHorizon => {
+                &[(IngredientTaste::Sweet, 0.5), (IngredientTaste::Spicy, 0.5)]
+            }
+        }
+    }
+}
+
 impl From<Glass> for Drink {
     fn from(glass: Glass) -> Self {
+        let prepared = glass.technique.unwrap_or_default();
+        let technique = prepared.technique;
+        let fill = fill_score(glass.get_current_volume(), glass.capacity);
+        let taste_mix = glass.taste.clone();
         let mut taste_vec: Vec<(IngredientTaste, f32)> = glass.taste.into_iter().collect();
         taste_vec.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
         let primary_taste = taste_vec
-            .get(0)
+            .first()
             .map(|(t, _)| *t)
             .unwrap_or(IngredientTaste::None);
         let secondary_taste = taste_vec
@@ -58,14 +132,14 @@ impl From<Glass> for Drink {
             .unwrap_or(IngredientTaste::None);
 
         let tastes = DrinkTaste {
-            primary_taste: primary_taste,
-            secondary_taste: secondary_taste,
+            primary_taste,
+            secondary_taste,
         };
 
         let mut effects_vec: Vec<(PrimaryEffect, f32)> = glass.effect.into_iter().collect();
         effects_vec.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
         let primary_effect = effects_vec
-            .get(0)
+            .first()
             .map(|(t, _)| *t)
             .unwrap_or(PrimaryEffect::Calming);
         let secondary_effect = effects_vec
@@ -73,25 +147,35 @@ impl From<Glass> for Drink {
             .map(|(t, _)| *t)
             .unwrap_or(PrimaryEffect::Calming);
 
+        let primary_volume = effects_vec.first().map(|(_, v)| *v).unwrap_or(0.0);
         let effects = DrinkEffect {
-            primary_effect: primary_effect,
-            secondary_effect: secondary_effect,
+            primary_effect,
+            secondary_effect,
+            strength: primary_volume / 10.0 * prepared.strength_multiplier(),
         };
 
         let created_drink = match glass.shape {
             GlassShape::Wine => match (primary_taste, secondary_taste) {
                 (IngredientTaste::Sour, _) => CreatedDrink::BinaryBarrel,
                 (IngredientTaste::Umami, _) => CreatedDrink::BotanicalSurge,
-                (IngredientTaste::Sweet, IngredientTaste::Spicy) => CreatedDrink::EventHorizon,
+                (IngredientTaste::Sweet, IngredientTaste::Spicy)
+                    if technique == Technique::Layer =>
+                {
+                    CreatedDrink::EventHorizon
+                }
                 _ => CreatedDrink::StellarLumen,
             },
             GlassShape::Whiskey => match (primary_taste, secondary_taste) {
                 (IngredientTaste::Umami, _) => CreatedDrink::EchoBloom,
-                (IngredientTaste::Bitter, _) => CreatedDrink::OldMemory,
+                (IngredientTaste::Bitter, _) if technique == Technique::Stir => {
+                    CreatedDrink::OldMemory
+                }
                 _ => CreatedDrink::CryoDrop,
             },
             GlassShape::Cocktail => match (primary_taste, secondary_taste) {
-                (IngredientTaste::Citrus, _) => CreatedDrink::Cosmopolitan,
+                (IngredientTaste::Citrus, _) if technique == Technique::Shake => {
+                    CreatedDrink::Cosmopolitan
+                }
                 (IngredientTaste::Spicy, _) => CreatedDrink::SynthCascade,
                 _ => CreatedDrink::ZeroPhase,
             },
@@ -100,8 +184,22 @@ impl From<Glass> for Drink {
             name: format!("{:#?}", created_drink),
             ingredients: glass.ingredients,
             taste: tastes,
-            created_drink: created_drink,
+            created_drink,
             effect: effects,
+            technique,
+            technique_quality: prepared.quality,
+            temperature: glass.temperature,
+            quality: DrinkQuality {
+                fill,
+                balance: balance_score(&taste_mix, created_drink.ideal_tastes()),
+                technique: prepared.quality,
+                temperature: temperature_score(
+                    glass.temperature,
+                    created_drink.ideal_temperature(),
+                ),
+            },
+            secondary_effects: glass.secondary_effects,
+            reactions: glass.reactions,
         }
     }
 }
@@ -116,6 +214,10 @@ pub enum HazardEffect {
     CloneEffect,
 }
 
+/// The drink and its burst pop up from this scale when presented.
+const PRESENT_FROM_SCALE: f32 = 0.2;
+const PRESENT_SECONDS: f32 = 0.4;
+
 pub fn spawn_crafted_drink(commands: &mut Commands, drink: Drink, image_assets: &ImageAssets) {
     let drink_image = match drink.created_drink {
         CreatedDrink::ZeroPhase => image_assets.zero_phase.clone(),
@@ -138,7 +240,15 @@ pub fn spawn_crafted_drink(commands: &mut Commands, drink: Drink, image_assets:
                 custom_size: Some(Vec2::new(256., 256.)),
                 ..Default::default()
             },
-            Transform::from_xyz(0.0, 0.0, 2.0),
+            Transform::from_xyz(0.0, 0.0, 2.0).with_scale(Vec3::splat(PRESENT_FROM_SCALE)),
+            Tween::new(
+                TweenTarget::Scale {
+                    from: Vec3::splat(PRESENT_FROM_SCALE),
+                    to: Vec3::ONE,
+                },
+                PRESENT_SECONDS,
+                Ease::BackOut,
+            ),
             Pickable::default(),
             DrinkSprite,
         ))
@@ -153,7 +263,74 @@ pub fn spawn_crafted_drink(commands: &mut Commands, drink: Drink, image_assets:
             custom_size: Some(Vec2::new(512., 512.)),
             ..Default::default()
         },
-        Transform::from_xyz(0.0, 0.0, 1.9),
+        Transform::from_xyz(0.0, 0.0, 1.9).with_scale(Vec3::splat(PRESENT_FROM_SCALE)),
+        Tween::new(
+            TweenTarget::Scale {
+                from: Vec3::splat(PRESENT_FROM_SCALE),
+                to: Vec3::ONE,
+            },
+            PRESENT_SECONDS * 1.5,
+            Ease::ElasticOut,
+        ),
         DrinkSprite,
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bar::technique::PreparedTechnique;
+
+    fn glass(shape: GlassShape, tastes: &[(IngredientTaste, f32)], technique: Technique) -> Glass {
+        Glass {
+            capacity: 10.0,
+            shape,
+            ingredients: HashMap::new(),
+            taste: tastes.iter().copied().collect(),
+            effect: HashMap::new(),
+            technique: Some(PreparedTechnique {
+                technique,
+                quality: 1.0,
+            }),
+            temperature: ROOM_TEMPERATURE,
+            secondary_effects: Vec::new(),
+            reactions: Vec::new(),
+        }
+    }
+
+    fn crafted(glass: Glass) -> CreatedDrink {
+        Drink::from(glass).created_drink
+    }
+
+    #[test]
+    fn technique_recipes_need_their_technique() {
+        let sweet_spicy = [(IngredientTaste::Sweet, 5.0), (IngredientTaste::Spicy, 4.0)];
+        let bitter = [(IngredientTaste::Bitter, 5.0)];
+        let citrus = [(IngredientTaste::Citrus, 5.0)];
+        let cases = [
+            (GlassShape::Wine, &sweet_spicy[..], Technique::Layer),
+            (GlassShape::Whiskey, &bitter[..], Technique::Stir),
+            (GlassShape::Cocktail, &citrus[..], Technique::Shake),
+        ];
+        for (shape, tastes, technique) in cases {
+            assert_ne!(
+                crafted(glass(shape.clone(), tastes, technique)),
+                crafted(glass(shape.clone(), tastes, Technique::Build)),
+                "{technique:?} in a {shape:?} glass",
+            );
+        }
+    }
+
+    #[test]
+    fn technique_recipes_fall_back_to_the_plain_drink() {
+        let sweet_spicy = [(IngredientTaste::Sweet, 5.0), (IngredientTaste::Spicy, 4.0)];
+        assert_eq!(
+            crafted(glass(GlassShape::Wine, &sweet_spicy, Technique::Layer)),
+            CreatedDrink::EventHorizon
+        );
+        assert_eq!(
+            crafted(glass(GlassShape::Wine, &sweet_spicy, Technique::Shake)),
+            CreatedDrink::StellarLumen
+        );
+    }
+}
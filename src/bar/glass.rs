@@ -9,9 +9,16 @@ use bevy::{
 };
 
 use crate::{
-    bar::crafting::OnCraftingScreen,
-    engine::asset_loader::ImageAssets,
-    ingredients::{IngredientTaste, PrimaryEffect},
+    bar::{
+        crafting::OnCraftingScreen, reactions::Reaction, technique::PreparedTechnique,
+        temperature::ROOM_TEMPERATURE,
+    },
+    engine::{
+        asset_loader::ImageAssets,
+        layout::{AnchoredTo, Surface},
+    },
+    ingredients::{IngredientTaste, PrimaryEffect, SecondaryEffect},
+    ui::navigation::{Focusable, NavActivate},
 };
 
 #[derive(Component, Clone, Debug)]
@@ -22,6 +29,14 @@ pub struct Glass {
     pub ingredients: HashMap<Entity, f32>,
     pub taste: HashMap<IngredientTaste, f32>,
     pub effect: HashMap<PrimaryEffect, f32>,
+    /// Set by the preparation mini-game, cleared by any further pour.
+    pub technique: Option<PreparedTechnique>,
+    /// Degrees Celsius, mixed on every pour and drifting while crafting.
+    pub temperature: f32,
+    /// Secondary effects whose volume and catalyst conditions are met.
+    pub secondary_effects: Vec<SecondaryEffect>,
+    /// Reactions currently shaping the mix.
+    pub reactions: Vec<Reaction>,
 }
 
 impl Glass {
@@ -32,6 +47,10 @@ impl Glass {
         self.ingredients.clear();
         self.taste.clear();
         self.effect.clear();
+        self.technique = None;
+        self.temperature = ROOM_TEMPERATURE;
+        self.secondary_effects.clear();
+        self.reactions.clear();
     }
 }
 
@@ -54,36 +73,41 @@ pub fn spawn_glass(mut commands: Commands, image_assets: Res<ImageAssets>) {
         ingredients: HashMap::new(),
         taste: HashMap::new(),
         effect: HashMap::new(),
+        technique: None,
+        temperature: ROOM_TEMPERATURE,
+        secondary_effects: Vec::new(),
+        reactions: Vec::new(),
     };
     commands
         .spawn((
             crafting_glass,
             glass_sprite,
-            Transform::from_translation(Vec3::new(200., -210., 1.)),
+            Transform::from_xyz(0.0, 0.0, 1.0),
+            AnchoredTo::new(Surface::Counter, "glass"),
             OnCraftingScreen,
+            Focusable,
             Pickable::default(),
         ))
-        .observe(
-            |event: Trigger<Pointer<Click>>,
-             mut query: Query<(&mut Glass, &mut Sprite)>,
-             image_assets: Res<ImageAssets>| {
-                if let Ok((mut glass, mut sprite)) = query.get_mut(event.target) {
-                    let (next_shape, new_image) = match glass.shape {
-                        GlassShape::Wine => {
-                            (GlassShape::Whiskey, image_assets.whiskey_glass.clone())
-                        }
-                        GlassShape::Whiskey => {
-                            (GlassShape::Cocktail, image_assets.cocktail_glass.clone())
-                        }
-                        GlassShape::Cocktail => (GlassShape::Wine, image_assets.wine_glass.clone()),
-                    };
-                    info!(
-                        "Switched glass shape from {:?} to {:?}",
-                        glass.shape, next_shape
-                    );
-                    glass.shape = next_shape;
-                    sprite.image = new_image;
-                }
-            },
+        .observe(cycle_glass_shape::<Pointer<Click>>)
+        .observe(cycle_glass_shape::<NavActivate>);
+}
+
+pub fn cycle_glass_shape<E: Event>(
+    event: Trigger<E>,
+    mut query: Query<(&mut Glass, &mut Sprite)>,
+    image_assets: Res<ImageAssets>,
+) {
+    if let Ok((mut glass, mut sprite)) = query.get_mut(event.target()) {
+        let (next_shape, new_image) = match glass.shape {
+            GlassShape::Wine => (GlassShape::Whiskey, image_assets.whiskey_glass.clone()),
+            GlassShape::Whiskey => (GlassShape::Cocktail, image_assets.cocktail_glass.clone()),
+            GlassShape::Cocktail => (GlassShape::Wine, image_assets.wine_glass.clone()),
+        };
+        info!(
+            "Switched glass shape from {:?} to {:?}",
+            glass.shape, next_shape
         );
+        glass.shape = next_shape;
+        sprite.image = new_image;
+    }
 }
@@ -2,3 +2,7 @@ pub mod bar_counter;
 pub mod crafting;
 pub mod drinks;
 pub mod glass;
+pub mod quality;
+pub mod reactions;
+pub mod technique;
+pub mod temperature;
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use crate::ingredients::IngredientTaste;
+
+/// Glasses filled to this share of their capacity count as full.
+const FULL_POUR: f32 = 0.8;
+
+const FILL_WEIGHT: f32 = 0.25;
+const BALANCE_WEIGHT: f32 = 0.35;
+const TECHNIQUE_WEIGHT: f32 = 0.2;
+const TEMPERATURE_WEIGHT: f32 = 0.2;
+
+pub const MAX_STARS: u8 = 5;
+
+/// How well a drink was made, each part from 0 to 1.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DrinkQuality {
+    pub fill: f32,
+    /// Closeness of the taste mix to the recipe's ideal proportions.
+    pub balance: f32,
+    pub technique: f32,
+    pub temperature: f32,
+}
+
+impl DrinkQuality {
+    pub fn score(&self) -> f32 {
+        self.fill * FILL_WEIGHT
+            + self.balance * BALANCE_WEIGHT
+            + self.technique * TECHNIQUE_WEIGHT
+            + self.temperature * TEMPERATURE_WEIGHT
+    }
+
+    /// One to five stars; even a poor drink gets one.
+    pub fn stars(&self) -> u8 {
+        (1 + (self.score() * (MAX_STARS - 1) as f32).round() as u8).min(MAX_STARS)
+    }
+}
+
+pub fn fill_score(volume: f32, capacity: f32) -> f32 {
+    if capacity <= 0.0 {
+        return 0.0;
+    }
+    (volume / capacity / FULL_POUR).clamp(0.0, 1.0)
+}
+
+/// 1 for an exact match of the ideal taste shares, 0 for no overlap at all.
+pub fn balance_score(
+    taste: &HashMap<IngredientTaste, f32>,
+    ideal: &[(IngredientTaste, f32)],
+) -> f32 {
+    let total: f32 = taste.values().sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+    let ideal_share = |key: &IngredientTaste| {
+        ideal
+            .iter()
+            .find(|(taste, _)| taste == key)
+            .map(|(_, share)| *share)
+            .unwrap_or(0.0)
+    };
+    let mut distance: f32 = taste
+        .iter()
+        .map(|(key, amount)| (amount / total - ideal_share(key)).abs())
+        .sum();
+    distance += ideal
+        .iter()
+        .filter(|(key, _)| !taste.contains_key(key))
+        .map(|(_, share)| share)
+        .sum::<f32>();
+    (1.0 - distance / 2.0).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quality(part: f32) -> DrinkQuality {
+        DrinkQuality {
+            fill: part,
+            balance: part,
+            technique: part,
+            temperature: part,
+        }
+    }
+
+    #[test]
+    fn weights_add_up_to_one() {
+        assert!((quality(1.0).score() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn stars_run_from_one_to_five() {
+        assert_eq!(quality(0.0).stars(), 1);
+        assert_eq!(quality(0.5).stars(), 3);
+        assert_eq!(quality(1.0).stars(), MAX_STARS);
+    }
+
+    #[test]
+    fn fill_counts_a_near_full_glass_as_full() {
+        assert_eq!(fill_score(80.0, 100.0), 1.0);
+        assert_eq!(fill_score(100.0, 100.0), 1.0);
+        assert!((fill_score(40.0, 100.0) - 0.5).abs() < 1e-6);
+        assert_eq!(fill_score(10.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn balance_compares_taste_shares() {
+        let ideal = [(IngredientTaste::Sweet, 0.5), (IngredientTaste::Sour, 0.5)];
+        let exact = HashMap::from([
+            (IngredientTaste::Sweet, 20.0),
+            (IngredientTaste::Sour, 20.0),
+        ]);
+        let lopsided = HashMap::from([
+            (IngredientTaste::Sweet, 30.0),
+            (IngredientTaste::Sour, 10.0),
+        ]);
+        let unrelated = HashMap::from([(IngredientTaste::Umami, 40.0)]);
+
+        assert!((balance_score(&exact, &ideal) - 1.0).abs() < 1e-6);
+        assert!((balance_score(&lopsided, &ideal) - 0.75).abs() < 1e-6);
+        assert_eq!(balance_score(&unrelated, &ideal), 0.0);
+        assert_eq!(balance_score(&HashMap::new(), &ideal), 0.0);
+    }
+}
@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    bar::glass::Glass,
+    ingredients::{Ingredient, IngredientId, IngredientTaste, PrimaryEffect},
+};
+
+/// Share of the glass lost when it foams over.
+const FOAM_SPILL: f32 = 0.3;
+/// Calming is multiplied by this while the catalyst is in the glass.
+const CATALYST_MULTIPLIER: f32 = 2.0;
+
+/// Something the mix does beyond adding up its ingredients.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Reaction {
+    /// Fizzion Mist meeting an icegel spills part of the glass, once.
+    FoamOver,
+    /// Sweetflux cancels out Citraplasm's citrus, volume for volume.
+    Neutralised,
+    /// Void Reserve doubles Calming without adding to it.
+    CatalysedCalming,
+}
+
+impl Reaction {
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Reaction::FoamOver => "reaction.foam_over",
+            Reaction::Neutralised => "reaction.neutralised",
+            Reaction::CatalysedCalming => "reaction.catalysed_calming",
+        }
+    }
+
+    fn applies(&self, volumes: &HashMap<IngredientId, f32>) -> bool {
+        let has = |id: IngredientId| volumes.get(&id).is_some_and(|volume| *volume > 0.0);
+        match self {
+            Reaction::FoamOver => {
+                has(IngredientId::FizzionMist) && volumes.keys().any(IngredientId::is_icegel)
+            }
+            Reaction::Neutralised => has(IngredientId::Sweetflux) && has(IngredientId::Citraplasm),
+            Reaction::CatalysedCalming => has(IngredientId::VoidReserve),
+        }
+    }
+}
+
+/// Pours `ingredient` into the glass and returns the reactions it set off.
+pub fn pour(
+    glass: &mut Glass,
+    ingredient_entity: Entity,
+    ingredient_query: &Query<&Ingredient>,
+) -> Vec<Reaction> {
+    let Ok(ingredient) = ingredient_query.get(ingredient_entity) else {
+        return Vec::new();
+    };
+    *glass.ingredients.entry(ingredient_entity).or_insert(0.0) +=
+        ingredient.ingredient_profile.size;
+    remix(glass, ingredient_query)
+}
+
+/// Rebuilds taste, effects and secondary effects from the poured ingredients,
+/// then applies every reaction. Returns the reactions that weren't active before.
+pub fn remix(glass: &mut Glass, ingredient_query: &Query<&Ingredient>) -> Vec<Reaction> {
+    let mut volumes: HashMap<IngredientId, f32> = HashMap::new();
+    for (entity, amount) in glass.ingredients.iter() {
+        if let Ok(ingredient) = ingredient_query.get(*entity) {
+            *volumes.entry(ingredient.id).or_insert(0.0) += amount;
+        }
+    }
+
+    let mut triggered = Vec::new();
+    // Foaming over happens once; what's left in the glass stays spilled.
+    if !glass.reactions.contains(&Reaction::FoamOver) && Reaction::FoamOver.applies(&volumes) {
+        for amount in glass.ingredients.values_mut() {
+            *amount *= 1.0 - FOAM_SPILL;
+        }
+        for volume in volumes.values_mut() {
+            *volume *= 1.0 - FOAM_SPILL;
+        }
+        triggered.push(Reaction::FoamOver);
+    }
+
+    glass.taste.clear();
+    glass.effect.clear();
+    glass.secondary_effects.clear();
+    for (entity, amount) in glass.ingredients.iter() {
+        let Ok(ingredient) = ingredient_query.get(*entity) else {
+            continue;
+        };
+        let profile = &ingredient.ingredient_profile;
+        *glass.taste.entry(profile.taste).or_insert(0.0) += amount;
+        *glass.effect.entry(profile.primary_effect).or_insert(0.0) += amount;
+        let condition = profile.secondary_effect.condition();
+        let has_catalyst = |id: IngredientId| volumes.contains_key(&id);
+        if condition.is_met(*amount, has_catalyst) {
+            glass
+                .secondary_effects
+                .push(profile.secondary_effect.clone());
+        }
+    }
+
+    if Reaction::Neutralised.applies(&volumes) {
+        let sweetflux = volumes[&IngredientId::Sweetflux];
+        let citrus = glass.taste.entry(IngredientTaste::Citrus).or_insert(0.0);
+        let neutralised = citrus.min(sweetflux);
+        *citrus -= neutralised;
+        *glass.taste.entry(IngredientTaste::None).or_insert(0.0) += neutralised;
+        glass.taste.retain(|_, amount| *amount > 0.0);
+        triggered.push(Reaction::Neutralised);
+    }
+    if Reaction::CatalysedCalming.applies(&volumes)
+        && let Some(calming) = glass
+            .effect
+            .get_mut(&PrimaryEffect::Calming)
+            .filter(|calming| **calming > 0.0)
+    {
+        *calming *= CATALYST_MULTIPLIER;
+        triggered.push(Reaction::CatalysedCalming);
+    }
+
+    let mut active: Vec<Reaction> = triggered.clone();
+    if glass.reactions.contains(&Reaction::FoamOver) {
+        active.insert(0, Reaction::FoamOver);
+    }
+    triggered.retain(|reaction| !glass.reactions.contains(reaction));
+    glass.reactions = active;
+    triggered
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+
+    use super::*;
+    use crate::{
+        bar::{glass::GlassShape, temperature::ROOM_TEMPERATURE},
+        ingredients::{EffectCondition, IngredientProfile, SecondaryEffect},
+    };
+
+    fn ingredient(
+        id: IngredientId,
+        taste: IngredientTaste,
+        primary_effect: PrimaryEffect,
+    ) -> Ingredient {
+        Ingredient {
+            id,
+            name: String::new(),
+            description: String::new(),
+            ingredient_profile: IngredientProfile {
+                size: 10.0,
+                taste,
+                primary_effect,
+                secondary_effect: SecondaryEffect::Sedated(EffectCondition {
+                    volume_needed: 1000.0,
+                    catalyst: None,
+                }),
+                hazard: None,
+                temperature: ROOM_TEMPERATURE,
+            },
+        }
+    }
+
+    fn glass(ingredients: &[(Entity, f32)]) -> Glass {
+        Glass {
+            capacity: 100.0,
+            shape: GlassShape::Cocktail,
+            ingredients: ingredients.iter().copied().collect(),
+            taste: HashMap::new(),
+            effect: HashMap::new(),
+            technique: None,
+            temperature: ROOM_TEMPERATURE,
+            secondary_effects: Vec::new(),
+            reactions: Vec::new(),
+        }
+    }
+
+    /// Runs `remix` against ingredients spawned into a throwaway world.
+    fn remix_in(world: &mut World, glass: &mut Glass) -> Vec<Reaction> {
+        let mut state = SystemState::<Query<&Ingredient>>::new(world);
+        let query = state.get(world);
+        remix(glass, &query)
+    }
+
+    #[test]
+    fn foam_over_spills_only_once() {
+        let mut world = World::new();
+        let mist = world
+            .spawn(ingredient(
+                IngredientId::FizzionMist,
+                IngredientTaste::Sour,
+                PrimaryEffect::Energizing,
+            ))
+            .id();
+        let icegel = world
+            .spawn(ingredient(
+                IngredientId::BlueIcegel,
+                IngredientTaste::None,
+                PrimaryEffect::Calming,
+            ))
+            .id();
+        let mut glass = glass(&[(mist, 10.0), (icegel, 10.0)]);
+
+        assert_eq!(remix_in(&mut world, &mut glass), [Reaction::FoamOver]);
+        assert!((glass.get_current_volume() - 14.0).abs() < 1e-4);
+
+        assert!(remix_in(&mut world, &mut glass).is_empty());
+        assert!((glass.get_current_volume() - 14.0).abs() < 1e-4);
+        assert_eq!(glass.reactions, [Reaction::FoamOver]);
+    }
+
+    #[test]
+    fn neutralisation_is_capped_by_sweetflux() {
+        let mut world = World::new();
+        let sweetflux = world
+            .spawn(ingredient(
+                IngredientId::Sweetflux,
+                IngredientTaste::Sweet,
+                PrimaryEffect::Healing,
+            ))
+            .id();
+        let citraplasm = world
+            .spawn(ingredient(
+                IngredientId::Citraplasm,
+                IngredientTaste::Citrus,
+                PrimaryEffect::MindEnhancing,
+            ))
+            .id();
+        let mut glass = glass(&[(sweetflux, 10.0), (citraplasm, 30.0)]);
+
+        assert_eq!(remix_in(&mut world, &mut glass), [Reaction::Neutralised]);
+        assert_eq!(glass.taste[&IngredientTaste::Citrus], 20.0);
+        assert_eq!(glass.taste[&IngredientTaste::None], 10.0);
+        assert_eq!(glass.taste[&IngredientTaste::Sweet], 10.0);
+    }
+
+    #[test]
+    fn catalyst_multiplies_calming() {
+        let mut world = World::new();
+        let void_reserve = world
+            .spawn(ingredient(
+                IngredientId::VoidReserve,
+                IngredientTaste::Umami,
+                PrimaryEffect::Calming,
+            ))
+            .id();
+        let icegel = world
+            .spawn(ingredient(
+                IngredientId::RedIcegel,
+                IngredientTaste::None,
+                PrimaryEffect::Calming,
+            ))
+            .id();
+        let mut glass = glass(&[(void_reserve, 10.0), (icegel, 20.0)]);
+
+        assert_eq!(
+            remix_in(&mut world, &mut glass),
+            [Reaction::CatalysedCalming]
+        );
+        assert_eq!(
+            glass.effect[&PrimaryEffect::Calming],
+            30.0 * CATALYST_MULTIPLIER
+        );
+    }
+
+    #[test]
+    fn catalyst_without_calming_is_not_reported() {
+        let mut world = World::new();
+        let void_reserve = world
+            .spawn(ingredient(
+                IngredientId::VoidReserve,
+                IngredientTaste::Umami,
+                PrimaryEffect::Energizing,
+            ))
+            .id();
+        let mut glass = glass(&[(void_reserve, 10.0)]);
+
+        assert!(remix_in(&mut world, &mut glass).is_empty());
+        assert_eq!(glass.effect[&PrimaryEffect::Energizing], 10.0);
+    }
+}
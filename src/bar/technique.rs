@@ -0,0 +1,94 @@
+/// How the drink is put together once everything is poured.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Technique {
+    /// Poured straight into the glass; the forgiving default.
+    #[default]
+    Build,
+    Stir,
+    Shake,
+    Layer,
+}
+
+/// Timing mini-game tuning for one technique.
+#[derive(Clone, Copy, Debug)]
+pub struct TechniqueSpec {
+    /// Marker sweeps across the bar this many times per second.
+    pub speed: f32,
+    /// Width of the target zone as a fraction of the bar.
+    pub zone_width: f32,
+    /// Target zone centres, one per round.
+    pub targets: &'static [f32],
+}
+
+impl Technique {
+    pub const ALL: [Technique; 4] = [
+        Technique::Shake,
+        Technique::Stir,
+        Technique::Build,
+        Technique::Layer,
+    ];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Technique::Build => "technique.build",
+            Technique::Stir => "technique.stir",
+            Technique::Shake => "technique.shake",
+            Technique::Layer => "technique.layer",
+        }
+    }
+
+    pub fn spec(&self) -> TechniqueSpec {
+        match self {
+            Technique::Build => TechniqueSpec {
+                speed: 0.6,
+                zone_width: 0.3,
+                targets: &[0.5],
+            },
+            Technique::Stir => TechniqueSpec {
+                speed: 0.8,
+                zone_width: 0.2,
+                targets: &[0.35, 0.65],
+            },
+            Technique::Shake => TechniqueSpec {
+                speed: 1.6,
+                zone_width: 0.2,
+                targets: &[0.5, 0.3, 0.7],
+            },
+            Technique::Layer => TechniqueSpec {
+                speed: 0.5,
+                zone_width: 0.08,
+                targets: &[0.6],
+            },
+        }
+    }
+}
+
+/// A finished preparation step, kept on the glass until it is crafted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PreparedTechnique {
+    pub technique: Technique,
+    /// Execution quality from 0 (botched) to 1 (perfect).
+    pub quality: f32,
+}
+
+/// A glass crafted without the preparation step counts as an average build.
+impl Default for PreparedTechnique {
+    fn default() -> Self {
+        Self {
+            technique: Technique::Build,
+            quality: 0.5,
+        }
+    }
+}
+
+impl PreparedTechnique {
+    /// Scales how strongly the drink's effects land.
+    pub fn strength_multiplier(&self) -> f32 {
+        0.5 + self.quality
+    }
+}
+
+/// Score for stopping the marker at `marker` when aiming for `target`.
+pub fn round_score(marker: f32, target: f32, zone_width: f32) -> f32 {
+    (1.0 - (marker - target).abs() / zone_width).clamp(0.0, 1.0)
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use crate::bar::glass::Glass;
+
+/// Degrees Celsius of the bar itself; bottles pour at it and glasses drift back to it.
+pub const ROOM_TEMPERATURE: f32 = 18.0;
+/// Icegels pour well below freezing.
+pub const ICEGEL_TEMPERATURE: f32 = -12.0;
+/// Fraction of the gap to room temperature closed per second.
+const DRIFT_RATE: f32 = 0.02;
+/// Degrees off the ideal at which a serving stops scoring at all.
+const TOLERANCE: f32 = 15.0;
+
+/// Temperature of `volume` at `current` after pouring `added` at `added_temperature`.
+pub fn mix_temperature(current: f32, volume: f32, added_temperature: f32, added: f32) -> f32 {
+    if volume + added <= 0.0 {
+        return current;
+    }
+    (current * volume + added_temperature * added) / (volume + added)
+}
+
+/// 1 when served at `ideal`, falling to 0 at the edge of the tolerance.
+pub fn temperature_score(actual: f32, ideal: f32) -> f32 {
+    (1.0 - (actual - ideal).abs() / TOLERANCE).clamp(0.0, 1.0)
+}
+
+/// Warms or cools the glass towards room temperature while the player works.
+pub fn drift_glass_temperature(time: Res<Time>, mut glass_query: Query<&mut Glass>) {
+    let rate = (DRIFT_RATE * time.delta_secs()).min(1.0);
+    for mut glass in glass_query.iter_mut() {
+        if glass.get_current_volume() <= 0.0 {
+            continue;
+        }
+        let temperature = glass.temperature + (ROOM_TEMPERATURE - glass.temperature) * rate;
+        // Only flag a change once the shown whole degree moves, so the glass
+        // panel isn't rebuilt every frame.
+        if temperature.round() != glass.temperature.round() {
+            glass.temperature = temperature;
+        } else {
+            glass.bypass_change_detection().temperature = temperature;
+        }
+    }
+}
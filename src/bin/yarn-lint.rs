@@ -0,0 +1,33 @@
+//! Checks the dialogue scripts without starting the game.
+//!
+//! Usage: `cargo run --bin yarn-lint [dir]`, with `dir` defaulting to `assets/dialogue`.
+
+use std::{path::PathBuf, process::ExitCode};
+
+#[path = "../dialogues/yarn_lint.rs"]
+mod yarn_lint;
+
+fn main() -> ExitCode {
+    let dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets/dialogue"));
+    let files = match yarn_lint::read_yarn_files(&dir) {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", dir.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let diagnostics = yarn_lint::lint(&files);
+    for diagnostic in diagnostics.iter() {
+        eprintln!("{diagnostic}");
+    }
+    if diagnostics.is_empty() {
+        println!("{} Yarn file(s) OK", files.len());
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("{} problem(s) found", diagnostics.len());
+        ExitCode::FAILURE
+    }
+}
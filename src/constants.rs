@@ -2,13 +2,16 @@ use bevy::prelude::*;
 
 pub const NORMAL_BUTTON: Color = Color::srgb(0.10, 0.07, 0.20);
 pub const HOVERED_BUTTON: Color = Color::srgb(0.15, 0.10, 0.30);
+pub const PRESSED_BUTTON: Color = Color::srgb(0.22, 0.15, 0.42);
+pub const DISABLED_BUTTON: Color = Color::srgb(0.12, 0.12, 0.14);
 pub const INPUT_FIELD_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
 pub const INPUT_FIELD_BG: Color = Color::srgb(0.50, 0.75, 0.40);
-pub const WHITE: Color = Color::srgb(0.00, 0.00, 0.00);
 pub const BUTTON_BORDER: Color = Color::srgb(0.10, 0.10, 0.40);
 pub const TEXT_COLOR: Color = Color::srgb(0.10, 0.80, 0.60);
+pub const DISABLED_TEXT_COLOR: Color = Color::srgb(0.35, 0.40, 0.40);
 
+pub const WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
+pub const BLACK: Color = Color::srgb(0.0, 0.0, 0.0);
 pub const RED: Color = Color::srgb(1.0, 0.0, 0.0);
-pub const BLUE: Color = Color::srgb(0.0, 1.0, 0.0);
-pub const GREEN: Color = Color::srgb(0.0, 0.0, 1.0);
-//check
+pub const GREEN: Color = Color::srgb(0.0, 1.0, 0.0);
+pub const BLUE: Color = Color::srgb(0.0, 0.0, 1.0);
@@ -1,7 +1,14 @@
 use bevy::prelude::*;
 
 use crate::{
-    customers::{Customer, Personality},
+    bar::temperature::ROOM_TEMPERATURE,
+    customers::{
+        Customer, Personality,
+        expressions::{
+            BARTENDER_SHEET, CARL_SHEET, CODA_SHEET, CharacterExpressions, ZARA_SHEET,
+            build_expression_atlas,
+        },
+    },
     dialogues::DialogueState,
     engine::asset_loader::ImageAssets,
     ingredients::IngredientTaste,
@@ -10,95 +17,123 @@ use crate::{
 pub fn get_character_sprites(
     dialogue_state: Res<State<DialogueState>>,
     image_assets: Res<ImageAssets>,
-) -> Vec<(Customer, Sprite, Transform)> {
+    mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+) -> Vec<(Customer, Sprite, Transform, CharacterExpressions)> {
     let mut chars = Vec::new();
     let bartender = Customer {
         name: "Bartender".to_string(),
         preferred_taste: IngredientTaste::Spicy,
         disliked_taste: IngredientTaste::Sweet,
+        preferred_temperature: ROOM_TEMPERATURE,
         satisfaction_score: 100.0,
         current_drink: None,
         dialogue_node: None,
         base_personality: Personality::Artificial,
     };
+    let (bartender_atlas, bartender_expressions) =
+        build_expression_atlas(&BARTENDER_SHEET, &mut texture_atlases);
     let bartender_sprite = Sprite {
         image: image_assets.bartenter_full.clone(),
+        texture_atlas: Some(bartender_atlas),
         custom_size: Some(Vec2::new(192.0, 256.0)),
         ..default()
     };
     let bartender_transform = Transform::from_translation(Vec3::new(400., 0., 1.));
     let customer_transform = Transform::from_translation(Vec3::new(-400., 0., 1.));
 
-    chars.push((bartender, bartender_sprite, bartender_transform));
+    chars.push((
+        bartender,
+        bartender_sprite,
+        bartender_transform,
+        bartender_expressions,
+    ));
     match dialogue_state.get() {
         DialogueState::BartenderMonologue => {}
         DialogueState::CarlEnters => {
-            let (carl, carl_sprite) = get_carl(image_assets);
-            chars.push((carl, carl_sprite, customer_transform));
+            let (carl, carl_sprite, expressions) = get_carl(image_assets, &mut texture_atlases);
+            chars.push((carl, carl_sprite, customer_transform, expressions));
         }
         DialogueState::ZaraEnters => {
-            let (carl, carl_sprite) = get_zara(image_assets);
-            chars.push((carl, carl_sprite, customer_transform));
+            let (carl, carl_sprite, expressions) = get_zara(image_assets, &mut texture_atlases);
+            chars.push((carl, carl_sprite, customer_transform, expressions));
         }
         DialogueState::CodaEnters => {
-            let (carl, carl_sprite) = get_coda(image_assets);
-            chars.push((carl, carl_sprite, customer_transform));
+            let (carl, carl_sprite, expressions) = get_coda(image_assets, &mut texture_atlases);
+            chars.push((carl, carl_sprite, customer_transform, expressions));
         }
         DialogueState::MysteryEnters => todo!(),
     }
     chars
 }
 
-fn get_carl(image_assets: Res<ImageAssets>) -> (Customer, Sprite) {
+fn get_carl(
+    image_assets: Res<ImageAssets>,
+    texture_atlases: &mut Assets<TextureAtlasLayout>,
+) -> (Customer, Sprite, CharacterExpressions) {
     let carl = Customer {
         name: "Carl".to_string(),
         preferred_taste: IngredientTaste::Spicy,
         disliked_taste: IngredientTaste::Umami,
+        preferred_temperature: 12.0,
         satisfaction_score: 100.0,
         current_drink: None,
         dialogue_node: None,
         base_personality: Personality::Creative,
     };
+    let (atlas, expressions) = build_expression_atlas(&CARL_SHEET, texture_atlases);
     let carl_sprite = Sprite {
         image: image_assets.carl_full.clone(),
+        texture_atlas: Some(atlas),
         custom_size: Some(Vec2::new(192.0, 256.0)),
         ..default()
     };
-    (carl, carl_sprite)
+    (carl, carl_sprite, expressions)
 }
 
-fn get_zara(image_assets: Res<ImageAssets>) -> (Customer, Sprite) {
+fn get_zara(
+    image_assets: Res<ImageAssets>,
+    texture_atlases: &mut Assets<TextureAtlasLayout>,
+) -> (Customer, Sprite, CharacterExpressions) {
     let zara = Customer {
         name: "Zara".to_string(),
         preferred_taste: IngredientTaste::Bitter,
         disliked_taste: IngredientTaste::Spicy,
+        preferred_temperature: 4.0,
         satisfaction_score: 100.0,
         current_drink: None,
         dialogue_node: None,
         base_personality: Personality::Volatile,
     };
+    let (atlas, expressions) = build_expression_atlas(&ZARA_SHEET, texture_atlases);
     let zara_sprite = Sprite {
         image: image_assets.zara.clone(),
+        texture_atlas: Some(atlas),
         custom_size: Some(Vec2::new(192.0, 256.0)),
         ..default()
     };
-    (zara, zara_sprite)
+    (zara, zara_sprite, expressions)
 }
 
-fn get_coda(image_assets: Res<ImageAssets>) -> (Customer, Sprite) {
+fn get_coda(
+    image_assets: Res<ImageAssets>,
+    texture_atlases: &mut Assets<TextureAtlasLayout>,
+) -> (Customer, Sprite, CharacterExpressions) {
     let coda = Customer {
         name: "Coda".to_string(),
         preferred_taste: IngredientTaste::Sweet,
         disliked_taste: IngredientTaste::Umami,
+        preferred_temperature: -2.0,
         satisfaction_score: 100.0,
         current_drink: None,
         dialogue_node: None,
         base_personality: Personality::Creative,
     };
+    let (atlas, expressions) = build_expression_atlas(&CODA_SHEET, texture_atlases);
     let coda_sprite = Sprite {
         image: image_assets.coda.clone(),
+        texture_atlas: Some(atlas),
         custom_size: Some(Vec2::new(192.0, 256.0)),
         ..default()
     };
-    (coda, coda_sprite)
+    (coda, coda_sprite, expressions)
 }
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::customers::Customer;
+
+pub const NEUTRAL_EXPRESSION: &str = "neutral";
+
+/// Maps expression names to frames of a character's expression sheet.
+#[derive(Component, Clone, Debug)]
+pub struct CharacterExpressions {
+    pub frames: HashMap<String, usize>,
+}
+
+impl CharacterExpressions {
+    pub fn from_sheet(sheet: &ExpressionSheet) -> Self {
+        let frames = sheet
+            .expressions
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (name.to_string(), index))
+            .collect();
+        Self { frames }
+    }
+
+    pub fn neutral_index(&self) -> usize {
+        self.frames.get(NEUTRAL_EXPRESSION).copied().unwrap_or(0)
+    }
+}
+
+/// Frames are laid out left to right, one column per expression.
+pub struct ExpressionSheet {
+    pub frame_size: UVec2,
+    pub expressions: &'static [&'static str],
+}
+
+/// Size of one frame in the character portraits.
+const PORTRAIT_FRAME: UVec2 = UVec2::new(1024, 1536);
+
+// Each portrait holds a single neutral frame so far. Scripts may already ask
+// for other expressions; those fall back to neutral until the frames are
+// added here, in the order they sit on the sheet.
+pub const BARTENDER_SHEET: ExpressionSheet = ExpressionSheet {
+    frame_size: PORTRAIT_FRAME,
+    expressions: &[NEUTRAL_EXPRESSION],
+};
+
+pub const CARL_SHEET: ExpressionSheet = ExpressionSheet {
+    frame_size: PORTRAIT_FRAME,
+    expressions: &[NEUTRAL_EXPRESSION],
+};
+
+pub const ZARA_SHEET: ExpressionSheet = ExpressionSheet {
+    frame_size: PORTRAIT_FRAME,
+    expressions: &[NEUTRAL_EXPRESSION],
+};
+
+pub const CODA_SHEET: ExpressionSheet = ExpressionSheet {
+    frame_size: PORTRAIT_FRAME,
+    expressions: &[NEUTRAL_EXPRESSION],
+};
+
+pub fn build_expression_atlas(
+    sheet: &ExpressionSheet,
+    texture_atlases: &mut Assets<TextureAtlasLayout>,
+) -> (TextureAtlas, CharacterExpressions) {
+    let layout = texture_atlases.add(TextureAtlasLayout::from_grid(
+        sheet.frame_size,
+        sheet.expressions.len() as u32,
+        1,
+        None,
+        None,
+    ));
+    let expressions = CharacterExpressions::from_sheet(sheet);
+    (
+        TextureAtlas {
+            layout,
+            index: expressions.neutral_index(),
+        },
+        expressions,
+    )
+}
+
+/// Yarn command: `<<expression Zara angry>>`
+pub fn set_expression(
+    In((character, expression)): In<(String, String)>,
+    mut query: Query<(&Customer, &CharacterExpressions, &mut Sprite)>,
+) {
+    let Some((_, expressions, mut sprite)) = query
+        .iter_mut()
+        .find(|(customer, _, _)| customer.name == character)
+    else {
+        warn!(
+            "No character named {} on screen for expression {}",
+            character, expression
+        );
+        return;
+    };
+    let index = match expressions.frames.get(&expression) {
+        Some(index) => *index,
+        None => {
+            warn!(
+                "{} has no {} expression, falling back to {}",
+                character, expression, NEUTRAL_EXPRESSION
+            );
+            expressions.neutral_index()
+        }
+    };
+    if let Some(texture_atlas) = &mut sprite.texture_atlas {
+        texture_atlas.index = index;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::{
+        bar::temperature::ROOM_TEMPERATURE, customers::Personality, ingredients::IngredientTaste,
+    };
+
+    const TEST_SHEET: ExpressionSheet = ExpressionSheet {
+        frame_size: PORTRAIT_FRAME,
+        expressions: &["smile", NEUTRAL_EXPRESSION, "annoyed"],
+    };
+
+    fn spawn_zara(world: &mut World, index: usize) -> Entity {
+        let customer = Customer {
+            name: "Zara".to_string(),
+            preferred_taste: IngredientTaste::Bitter,
+            disliked_taste: IngredientTaste::Spicy,
+            preferred_temperature: ROOM_TEMPERATURE,
+            satisfaction_score: 100.0,
+            current_drink: None,
+            dialogue_node: None,
+            base_personality: Personality::Volatile,
+        };
+        let sprite = Sprite {
+            texture_atlas: Some(TextureAtlas {
+                layout: Handle::default(),
+                index,
+            }),
+            ..default()
+        };
+        world
+            .spawn((
+                customer,
+                CharacterExpressions::from_sheet(&TEST_SHEET),
+                sprite,
+            ))
+            .id()
+    }
+
+    fn expression_index(world: &mut World, entity: Entity, expression: &str) -> usize {
+        world
+            .run_system_once_with(set_expression, ("Zara".to_string(), expression.to_string()))
+            .unwrap();
+        world
+            .get::<Sprite>(entity)
+            .unwrap()
+            .texture_atlas
+            .as_ref()
+            .unwrap()
+            .index
+    }
+
+    #[test]
+    fn known_expression_selects_its_frame() {
+        let mut world = World::new();
+        let zara = spawn_zara(&mut world, 0);
+        assert_eq!(expression_index(&mut world, zara, "annoyed"), 2);
+    }
+
+    #[test]
+    fn unknown_expression_falls_back_to_neutral() {
+        let mut world = World::new();
+        let zara = spawn_zara(&mut world, 0);
+        assert_eq!(expression_index(&mut world, zara, "furious"), 1);
+    }
+}
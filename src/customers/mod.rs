@@ -1,14 +1,19 @@
 use bevy::prelude::*;
 
 use crate::{
-    animation::{AnimationEvent, sprite_animation::animate_spite},
+    animation::tween::{Ease, Tween, TweenTarget},
+    bar::{drinks::Drink, temperature::temperature_score},
     customers::customer_sprites::get_character_sprites,
     dialogues::{DialogPlugin, DialogueState},
-    engine::{GameState, asset_loader::ImageAssets, audio_controller::play_customer_bg},
-    ingredients::{IngredientTaste, PrimaryEffect},
+    engine::{
+        GameState, asset_loader::ImageAssets, audio_controller::play_customer_bg,
+        layout::CoverViewport,
+    },
+    ingredients::IngredientTaste,
 };
 
 pub mod customer_sprites;
+pub mod expressions;
 
 #[derive(Component)]
 pub struct OnCustomerScreen;
@@ -22,12 +27,7 @@ impl Plugin for CustomerPlugin {
                 OnEnter(GameState::Dialogues),
                 (play_customer_bg, spawn_customer, spawn_bg),
             )
-            .add_systems(
-                Update,
-                (animate_spite).run_if(in_state(GameState::Dialogues)),
-            )
-            .add_systems(OnExit(GameState::Dialogues), cleanup_customer)
-            .add_event::<AnimationEvent>();
+            .add_systems(OnExit(GameState::Dialogues), cleanup_customer);
     }
 }
 
@@ -37,12 +37,46 @@ pub struct Customer {
     pub name: String,
     pub preferred_taste: IngredientTaste,
     pub disliked_taste: IngredientTaste,
+    /// Degrees Celsius they like their drinks served at.
+    pub preferred_temperature: f32,
     pub satisfaction_score: f32,
     pub current_drink: Option<Entity>,
     pub dialogue_node: Option<String>,
     pub base_personality: Personality,
 }
 
+/// Patrons slide in from this far off to the left of their spot.
+const ENTRANCE_DISTANCE: f32 = 1000.0;
+const ENTRANCE_SECONDS: f32 = 0.9;
+
+/// Most satisfaction a drink at the wrong temperature can cost.
+const TEMPERATURE_PENALTY: f32 = 20.0;
+/// Satisfaction gained or lost per star above or below an average three.
+const SATISFACTION_PER_STAR: f32 = 10.0;
+const TASTE_SATISFACTION: f32 = 10.0;
+
+impl Customer {
+    /// Adjusts satisfaction for a drink served to them.
+    pub fn taste_drink(&mut self, drink: &Drink) {
+        let score = temperature_score(drink.temperature, self.preferred_temperature);
+        self.satisfaction_score -= (1.0 - score) * TEMPERATURE_PENALTY;
+        self.satisfaction_score += (drink.quality.stars() as f32 - 3.0) * SATISFACTION_PER_STAR;
+        if drink.taste.primary_taste == self.preferred_taste {
+            self.satisfaction_score += TASTE_SATISFACTION;
+        } else if drink.taste.primary_taste == self.disliked_taste {
+            self.satisfaction_score -= TASTE_SATISFACTION;
+        }
+        info!(
+            "{} got a {} star drink at {:.1}°C (prefers {:.1}°C), satisfaction {:.0}",
+            self.name,
+            drink.quality.stars(),
+            drink.temperature,
+            self.preferred_temperature,
+            self.satisfaction_score
+        );
+    }
+}
+
 /// Base personalities for each patron
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Personality {
@@ -56,12 +90,32 @@ pub fn spawn_customer(
     mut commands: Commands,
     image_assets: Res<ImageAssets>,
     dialogue_state: Res<State<DialogueState>>,
+    texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+    mut last_arrival: Local<Option<DialogueState>>,
 ) {
-    let characters: Vec<(Customer, Sprite, Transform)> =
-        get_character_sprites(dialogue_state, image_assets);
+    // Patrons walk in once per visit, not every time the view returns from the counter.
+    let arriving = last_arrival.as_ref() != Some(dialogue_state.get());
+    *last_arrival = Some(dialogue_state.get().clone());
+    let characters = get_character_sprites(dialogue_state, image_assets, texture_atlases);
 
-    for (customer, sprite, transform) in characters {
-        commands.spawn((OnCustomerScreen, customer, sprite, transform));
+    for (customer, sprite, transform, expressions) in characters {
+        let walks_in = arriving && customer.base_personality != Personality::Artificial;
+        let mut entity =
+            commands.spawn((OnCustomerScreen, customer, sprite, transform, expressions));
+        if walks_in {
+            let from = transform.translation - Vec3::X * ENTRANCE_DISTANCE;
+            entity.insert((
+                Transform::from_translation(from),
+                Tween::new(
+                    TweenTarget::Translation {
+                        from,
+                        to: transform.translation,
+                    },
+                    ENTRANCE_SECONDS,
+                    Ease::CubicOut,
+                ),
+            ));
+        }
     }
 }
 
@@ -73,6 +127,8 @@ fn spawn_bg(mut commands: Commands, image_assets: Res<ImageAssets>) {
             ..default()
         },
         Transform::from_xyz(0.0, 0.0, -10.0),
+        CoverViewport,
+        OnCustomerScreen,
     ));
 }
 
@@ -0,0 +1,25 @@
+//! Names of the custom commands every dialogue runner registers. Kept free of
+//! Bevy so `yarn_lint` can include it with `#[path]` as well.
+
+pub const CHANGE_GAMESTATE: &str = "change_gamestate";
+pub const CHANGE_DIALOG_STATE: &str = "change_dialog_state";
+pub const CONSUME_DRINK: &str = "consume_drink";
+pub const EXPRESSION: &str = "expression";
+
+/// Arguments `change_gamestate` accepts.
+pub const GAME_STATE_CRAFTING: &str = "Crafting";
+pub const GAME_STATE_END: &str = "End";
+pub const GAME_STATE_MENU: &str = "Menu";
+pub const GAME_STATE_ARGS: &[&str] = &[GAME_STATE_CRAFTING, GAME_STATE_END, GAME_STATE_MENU];
+
+/// Arguments `change_dialog_state` accepts, one per patron.
+pub const DIALOGUE_STATE_CARL: &str = "Carl";
+pub const DIALOGUE_STATE_ZARA: &str = "Zara";
+pub const DIALOGUE_STATE_CODA: &str = "Coda";
+pub const DIALOGUE_STATE_MYSTERY: &str = "Mystery";
+pub const DIALOGUE_STATE_ARGS: &[&str] = &[
+    DIALOGUE_STATE_CARL,
+    DIALOGUE_STATE_ZARA,
+    DIALOGUE_STATE_CODA,
+    DIALOGUE_STATE_MYSTERY,
+];
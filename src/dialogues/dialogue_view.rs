@@ -0,0 +1,425 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_yarnspinner::{
+    events::{DialogueCompleteEvent, DialogueStartEvent, PresentLineEvent, PresentOptionsEvent},
+    prelude::*,
+};
+
+use crate::{
+    customers::OnCustomerScreen,
+    dialogues::history::history_closed,
+    engine::{GameState, asset_loader::ImageAssets, pause::PauseState},
+    ui::{
+        navigation::NavFocus,
+        widgets::{ButtonSize, LabelSize, PressedButtons, UiTheme},
+    },
+};
+
+/// Characters revealed per second while a line is being typed out.
+const DEFAULT_TEXT_SPEED: f32 = 40.0;
+
+pub struct DialogueViewPlugin;
+
+impl Plugin for DialogueViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<LinePresented>()
+            .add_event::<OptionSelected>()
+            .init_resource::<LinePresentation>()
+            .add_systems(OnEnter(GameState::Dialogues), spawn_dialogue_view)
+            .add_systems(
+                Update,
+                (
+                    show_view_on_start,
+                    present_line,
+                    present_options,
+                    type_line,
+                    (continue_dialogue, option_button_interaction)
+                        .run_if(history_closed.and(in_state(PauseState::Running))),
+                    hide_view_on_complete,
+                )
+                    .chain()
+                    .after(YarnSpinnerSystemSet)
+                    .run_if(in_state(GameState::Dialogues)),
+            );
+    }
+}
+
+/// Sent whenever the view starts presenting a new Yarn line.
+#[derive(Event, Debug, Clone)]
+pub struct LinePresented {
+    pub line_id: LineId,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+/// Sent when the player picks one of the presented options.
+#[derive(Event, Debug, Clone)]
+pub struct OptionSelected {
+    pub option_id: OptionId,
+    pub text: String,
+}
+
+#[derive(Component)]
+pub struct DialogueViewRoot;
+
+#[derive(Component)]
+pub struct SpeakerNamePlate;
+
+#[derive(Component)]
+pub struct SpeakerNameText;
+
+#[derive(Component)]
+pub struct DialogueLineText;
+
+#[derive(Component)]
+pub struct DialogueOptionsContainer;
+
+#[derive(Component)]
+pub struct ContinueIndicator;
+
+#[derive(Component)]
+pub struct OptionButton {
+    /// Position among the shown options, 0-based; number keys pick by this.
+    pub index: usize,
+    pub option_id: OptionId,
+    pub text: String,
+}
+
+/// The line currently being typed out by the view.
+#[derive(Resource)]
+pub struct LinePresentation {
+    pub full_text: String,
+    pub revealed: f32,
+    pub chars_per_second: f32,
+    pub awaiting_option: bool,
+}
+
+impl Default for LinePresentation {
+    fn default() -> Self {
+        Self {
+            full_text: String::new(),
+            revealed: 0.0,
+            chars_per_second: DEFAULT_TEXT_SPEED,
+            awaiting_option: false,
+        }
+    }
+}
+
+impl LinePresentation {
+    pub fn is_finished(&self) -> bool {
+        self.revealed as usize >= self.full_text.chars().count()
+    }
+
+    pub fn finish(&mut self) {
+        self.revealed = self.full_text.chars().count() as f32;
+    }
+
+    fn visible_text(&self) -> String {
+        self.full_text
+            .chars()
+            .take(self.revealed as usize)
+            .collect()
+    }
+}
+
+/// Name plate colour for each speaker in the script.
+pub fn speaker_color(speaker: &str) -> Color {
+    match speaker {
+        "Bartender" => Color::srgb(0.20, 0.45, 0.55),
+        "Carl" => Color::srgb(0.70, 0.40, 0.15),
+        "Zara" => Color::srgb(0.60, 0.15, 0.25),
+        "Coda" => Color::srgb(0.35, 0.55, 0.25),
+        "Player" => Color::srgb(0.25, 0.25, 0.45),
+        _ => Color::srgb(0.30, 0.30, 0.30),
+    }
+}
+
+fn spawn_dialogue_view(
+    mut commands: Commands,
+    image_assets: Res<ImageAssets>,
+    theme: Res<UiTheme>,
+    mut presentation: ResMut<LinePresentation>,
+) {
+    *presentation = LinePresentation {
+        chars_per_second: presentation.chars_per_second,
+        ..default()
+    };
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(20.0),
+                left: Val::Percent(10.0),
+                width: Val::Percent(80.0),
+                min_height: Val::Percent(28.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::axes(Val::Px(60.0), Val::Px(40.0)),
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            ImageNode {
+                image: image_assets.talk_background.clone(),
+                image_mode: NodeImageMode::Stretch,
+                ..default()
+            },
+            Visibility::Hidden,
+            DialogueViewRoot,
+            OnCustomerScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        align_self: AlignSelf::FlexStart,
+                        padding: UiRect::axes(Val::Px(16.0), Val::Px(6.0)),
+                        border: UiRect::all(Val::Px(3.0)),
+                        ..default()
+                    },
+                    BorderColor(theme.border),
+                    BorderRadius::all(Val::Px(8.0)),
+                    BackgroundColor(speaker_color("")),
+                    SpeakerNamePlate,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::default(),
+                        theme.text_font(LabelSize::Body),
+                        TextColor(theme.text_light),
+                        SpeakerNameText,
+                    ));
+                });
+            parent.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 26.0,
+                    ..theme.text_font(LabelSize::Body)
+                },
+                TextColor(theme.text_light),
+                DialogueLineText,
+            ));
+            parent.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                DialogueOptionsContainer,
+            ));
+            parent.spawn((
+                theme.label(">>", LabelSize::Small),
+                Node {
+                    align_self: AlignSelf::FlexEnd,
+                    ..default()
+                },
+                Visibility::Hidden,
+                ContinueIndicator,
+            ));
+        });
+}
+
+fn show_view_on_start(
+    mut start_events: EventReader<DialogueStartEvent>,
+    mut root_query: Query<&mut Visibility, With<DialogueViewRoot>>,
+) {
+    for _ in start_events.read() {
+        for mut visibility in root_query.iter_mut() {
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+fn present_line(
+    mut line_events: EventReader<PresentLineEvent>,
+    mut presentation: ResMut<LinePresentation>,
+    mut name_text: Query<&mut Text, With<SpeakerNameText>>,
+    mut name_plate: Query<(&mut BackgroundColor, &mut Visibility), With<SpeakerNamePlate>>,
+    mut line_presented: EventWriter<LinePresented>,
+) {
+    for event in line_events.read() {
+        let speaker = event.line.character_name().map(str::to_string);
+        let text = event.line.text_without_character_name();
+        for mut name in name_text.iter_mut() {
+            name.0 = speaker.clone().unwrap_or_default();
+        }
+        for (mut color, mut visibility) in name_plate.iter_mut() {
+            match &speaker {
+                Some(speaker) => {
+                    *color = speaker_color(speaker).into();
+                    *visibility = Visibility::Inherited;
+                }
+                None => *visibility = Visibility::Hidden,
+            }
+        }
+        presentation.full_text = text.clone();
+        presentation.revealed = 0.0;
+        presentation.awaiting_option = false;
+        line_presented.write(LinePresented {
+            line_id: event.line.id.clone(),
+            speaker,
+            text,
+        });
+    }
+}
+
+fn present_options(
+    mut commands: Commands,
+    mut option_events: EventReader<PresentOptionsEvent>,
+    theme: Res<UiTheme>,
+    mut presentation: ResMut<LinePresentation>,
+    container_query: Query<Entity, With<DialogueOptionsContainer>>,
+) {
+    for event in option_events.read() {
+        presentation.awaiting_option = true;
+        presentation.finish();
+        for container in container_query.iter() {
+            commands.entity(container).with_children(|parent| {
+                for (index, option) in event
+                    .options
+                    .iter()
+                    .filter(|option| option.is_available)
+                    .enumerate()
+                {
+                    let text = option.line.text_without_character_name();
+                    parent.spawn((
+                        theme.labelled_button(
+                            ButtonSize::Inline,
+                            format!("{}. {}", index + 1, text),
+                        ),
+                        OptionButton {
+                            index,
+                            option_id: option.id,
+                            text: text.clone(),
+                        },
+                    ));
+                }
+            });
+        }
+    }
+}
+
+fn type_line(
+    time: Res<Time>,
+    mut presentation: ResMut<LinePresentation>,
+    mut line_text: Query<&mut Text, With<DialogueLineText>>,
+    mut indicator: Query<&mut Visibility, With<ContinueIndicator>>,
+) {
+    if !presentation.is_finished() {
+        presentation.revealed += presentation.chars_per_second * time.delta_secs();
+    }
+    let visible = presentation.visible_text();
+    for mut text in line_text.iter_mut() {
+        if text.0 != visible {
+            text.0 = visible.clone();
+        }
+    }
+    for mut visibility in indicator.iter_mut() {
+        *visibility = if presentation.is_finished() && !presentation.awaiting_option {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+fn continue_dialogue(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    buttons: Query<&Interaction, With<Button>>,
+    nav_focus: Res<NavFocus>,
+    mut presentation: ResMut<LinePresentation>,
+    mut runners: Query<&mut DialogueRunner>,
+) {
+    // Clicks on any on-screen button belong to that button, not the dialogue.
+    let over_button = buttons
+        .iter()
+        .any(|interaction| *interaction != Interaction::None);
+    // With a focused button, Space and Enter confirm that button instead.
+    let advance = (nav_focus.0.is_none()
+        && (keys.just_pressed(KeyCode::Space) || keys.just_pressed(KeyCode::Enter)))
+        || (mouse.just_pressed(MouseButton::Left) && !over_button);
+    if !advance || presentation.awaiting_option {
+        return;
+    }
+    if !presentation.is_finished() {
+        presentation.finish();
+        return;
+    }
+    for mut runner in runners.iter_mut() {
+        if runner.is_running() && !runner.is_waiting_for_option_selection() {
+            runner.continue_in_next_update();
+        }
+    }
+}
+
+/// An option picked this frame, by click, navigation or its number key.
+#[derive(SystemParam)]
+struct OptionChoice<'w, 's> {
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    pressed: PressedButtons<'w, 's, OptionButton>,
+    option_buttons: Query<'w, 's, &'static OptionButton>,
+}
+
+impl OptionChoice<'_, '_> {
+    fn selected(&self) -> Option<(OptionId, String)> {
+        const NUMBER_KEYS: [KeyCode; 9] = [
+            KeyCode::Digit1,
+            KeyCode::Digit2,
+            KeyCode::Digit3,
+            KeyCode::Digit4,
+            KeyCode::Digit5,
+            KeyCode::Digit6,
+            KeyCode::Digit7,
+            KeyCode::Digit8,
+            KeyCode::Digit9,
+        ];
+        let by_key = self.option_buttons.iter().find(|button| {
+            NUMBER_KEYS
+                .get(button.index)
+                .is_some_and(|key| self.keys.just_pressed(*key))
+        });
+        by_key
+            .or_else(|| self.pressed.iter().next())
+            .map(|button| (button.option_id, button.text.clone()))
+    }
+}
+
+fn option_button_interaction(
+    mut commands: Commands,
+    choice: OptionChoice,
+    container_query: Query<Entity, With<DialogueOptionsContainer>>,
+    mut presentation: ResMut<LinePresentation>,
+    mut runners: Query<&mut DialogueRunner>,
+    mut option_selected: EventWriter<OptionSelected>,
+) {
+    let selected = choice.selected();
+    let Some((option_id, text)) = selected else {
+        return;
+    };
+    for mut runner in runners.iter_mut() {
+        if !runner.is_waiting_for_option_selection() {
+            continue;
+        }
+        if let Err(err) = runner.select_option(option_id) {
+            warn!("Failed to select dialogue option: {}", err);
+            continue;
+        }
+        option_selected.write(OptionSelected {
+            option_id,
+            text: text.clone(),
+        });
+    }
+    presentation.awaiting_option = false;
+    for container in container_query.iter() {
+        commands.entity(container).despawn_related::<Children>();
+    }
+}
+
+fn hide_view_on_complete(
+    mut complete_events: EventReader<DialogueCompleteEvent>,
+    mut root_query: Query<&mut Visibility, With<DialogueViewRoot>>,
+) {
+    for _ in complete_events.read() {
+        for mut visibility in root_query.iter_mut() {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
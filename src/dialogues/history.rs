@@ -0,0 +1,231 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+};
+
+use crate::{
+    customers::OnCustomerScreen,
+    dialogues::dialogue_view::{LinePresented, OptionSelected},
+    engine::{GameState, pause::PauseState},
+    localization::Localization,
+    ui::widgets::{ButtonSize, LabelSize, PressedButtons, UiTheme},
+};
+
+const HISTORY_LINE_HEIGHT: f32 = 28.0;
+
+pub struct DialogueHistoryPlugin;
+
+impl Plugin for DialogueHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DialogueHistory>()
+            .add_systems(OnEnter(GameState::Loading), clear_history)
+            .add_systems(OnEnter(GameState::Dialogues), spawn_history_button)
+            .add_systems(
+                Update,
+                (
+                    record_history,
+                    (toggle_history, scroll_history).run_if(in_state(PauseState::Running)),
+                    update_history_button_label,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Dialogues)),
+            )
+            .add_systems(OnExit(GameState::Dialogues), close_history);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum HistoryEntry {
+    Line {
+        speaker: Option<String>,
+        text: String,
+    },
+    Choice {
+        text: String,
+    },
+}
+
+/// Every line and choice presented since the night started.
+#[derive(Resource, Default)]
+pub struct DialogueHistory {
+    pub entries: Vec<HistoryEntry>,
+    pub is_open: bool,
+}
+
+#[derive(Component)]
+pub struct HistoryButton;
+
+#[derive(Component)]
+pub struct HistoryPanel;
+
+#[derive(Component)]
+pub struct HistoryScroll;
+
+pub fn history_closed(history: Res<DialogueHistory>) -> bool {
+    !history.is_open
+}
+
+fn clear_history(mut history: ResMut<DialogueHistory>) {
+    history.entries.clear();
+    history.is_open = false;
+}
+
+fn record_history(
+    mut lines: EventReader<LinePresented>,
+    mut options: EventReader<OptionSelected>,
+    mut history: ResMut<DialogueHistory>,
+) {
+    for line in lines.read() {
+        history.entries.push(HistoryEntry::Line {
+            speaker: line.speaker.clone(),
+            text: line.text.clone(),
+        });
+    }
+    for option in options.read() {
+        history.entries.push(HistoryEntry::Choice {
+            text: option.text.clone(),
+        });
+    }
+}
+
+fn spawn_history_button(
+    mut commands: Commands,
+    localization: Res<Localization>,
+    theme: Res<UiTheme>,
+) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            right: Val::Px(20.0),
+            ..default()
+        },
+        OnCustomerScreen,
+        children![(
+            theme.labelled_button(ButtonSize::Medium, localization.get("dialogue.log")),
+            HistoryButton,
+        )],
+    ));
+}
+
+pub fn toggle_history(
+    mut commands: Commands,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut history: ResMut<DialogueHistory>,
+    history_button: PressedButtons<HistoryButton>,
+    panel_query: Query<Entity, With<HistoryPanel>>,
+    theme: Res<UiTheme>,
+) {
+    // Escape closes the log without also opening the pause menu.
+    let toggled = keys.just_pressed(KeyCode::KeyH)
+        || (history.is_open && keys.clear_just_pressed(KeyCode::Escape))
+        || history_button.iter().next().is_some();
+    if !toggled {
+        return;
+    }
+
+    history.is_open = !history.is_open;
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    if history.is_open {
+        spawn_history_panel(&mut commands, &history, &theme);
+    }
+}
+
+fn spawn_history_panel(commands: &mut Commands, history: &DialogueHistory, theme: &UiTheme) {
+    commands
+        .spawn((
+            theme.panel(Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(90.0),
+                right: Val::Px(20.0),
+                width: Val::Percent(40.0),
+                height: Val::Percent(60.0),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            }),
+            GlobalZIndex(10),
+            HistoryPanel,
+            OnCustomerScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        height: Val::Percent(100.0),
+                        row_gap: Val::Px(8.0),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                    ScrollPosition {
+                        offset_x: 0.0,
+                        // Start at the newest entry; layout clamps this to the content height.
+                        offset_y: f32::MAX,
+                    },
+                    HistoryScroll,
+                ))
+                .with_children(|parent| {
+                    for entry in &history.entries {
+                        let (text, color) = match entry {
+                            HistoryEntry::Line {
+                                speaker: Some(speaker),
+                                text,
+                            } => (format!("{}: {}", speaker, text), theme.text_light),
+                            HistoryEntry::Line {
+                                speaker: None,
+                                text,
+                            } => (text.clone(), theme.text_light),
+                            HistoryEntry::Choice { text } => (format!("> {}", text), theme.text),
+                        };
+                        parent.spawn((
+                            Text::new(text),
+                            theme.text_font(LabelSize::Small),
+                            TextColor(color),
+                        ));
+                    }
+                });
+        });
+}
+
+fn update_history_button_label(
+    history: Res<DialogueHistory>,
+    localization: Res<Localization>,
+    mut button_text: Query<&mut Text>,
+    button_query: Query<&Children, With<HistoryButton>>,
+) {
+    if !history.is_changed() {
+        return;
+    }
+    for children in button_query.iter() {
+        for child in children.iter() {
+            if let Ok(mut text) = button_text.get_mut(child) {
+                text.0 = localization.get(if history.is_open {
+                    "dialogue.log_close"
+                } else {
+                    "dialogue.log"
+                });
+            }
+        }
+    }
+}
+
+fn scroll_history(
+    mut wheel_events: EventReader<MouseWheel>,
+    mut scroll_query: Query<&mut ScrollPosition, With<HistoryScroll>>,
+) {
+    for event in wheel_events.read() {
+        let delta = match event.unit {
+            MouseScrollUnit::Line => event.y * HISTORY_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        };
+        for mut scroll in scroll_query.iter_mut() {
+            scroll.offset_y = (scroll.offset_y - delta).max(0.0);
+        }
+    }
+}
+
+fn close_history(mut history: ResMut<DialogueHistory>) {
+    history.is_open = false;
+}
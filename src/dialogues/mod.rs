@@ -1,8 +1,23 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 use bevy_yarnspinner::prelude::*;
-use bevy_yarnspinner_example_dialogue_view::prelude::*;
 
-use crate::{customers::OnCustomerScreen, engine::GameState, ui::crafting_ui::DrinkSprite};
+use crate::{
+    bar::drinks::Drink,
+    customers::{Customer, OnCustomerScreen, Personality, expressions::set_expression},
+    dialogues::{
+        commands as yarn_commands, dialogue_view::DialogueViewPlugin,
+        history::DialogueHistoryPlugin,
+    },
+    engine::{GameState, launch::LaunchOptions},
+    localization::{Locale, Localization},
+    ui::crafting_ui::DrinkSprite,
+};
+
+pub mod commands;
+pub mod dialogue_view;
+pub mod history;
 
 #[derive(States, Default, Debug, Hash, Eq, PartialEq, Clone)]
 pub enum DialogueState {
@@ -19,16 +34,61 @@ pub enum DialogueState {
     //MysteryDrinks,
 }
 
+impl DialogueState {
+    pub fn node_name(&self) -> &'static str {
+        match self {
+            DialogueState::BartenderMonologue => "BartenderMonologue",
+            DialogueState::CarlEnters => "CarlEnters",
+            DialogueState::ZaraEnters => "ZaraEnters",
+            DialogueState::CodaEnters => "CodaEnters",
+            DialogueState::MysteryEnters => "MysteryEnters",
+        }
+    }
+
+    /// Mystery has no sprites yet, so her scene cannot be entered directly.
+    pub fn is_playable(&self) -> bool {
+        !matches!(self, DialogueState::MysteryEnters)
+    }
+
+    pub fn from_node_name(key: &str) -> Option<Self> {
+        match key {
+            "BartenderMonologue" => Some(DialogueState::BartenderMonologue),
+            "CarlEnters" => Some(DialogueState::CarlEnters),
+            "ZaraEnters" => Some(DialogueState::ZaraEnters),
+            "CodaEnters" => Some(DialogueState::CodaEnters),
+            "MysteryEnters" => Some(DialogueState::MysteryEnters),
+            _ => None,
+        }
+    }
+}
+
+/// Yarn variables carried between dialogue runners for the rest of the night.
+#[derive(Resource, Default)]
+pub struct NightVariables(pub HashMap<String, YarnValue>);
+
 pub struct DialogPlugin;
 
 impl Plugin for DialogPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
-            YarnSpinnerPlugin::with_yarn_source(YarnFileSource::file("dialogue/on_the_rocks.yarn")),
-            ExampleYarnSpinnerDialogueViewPlugin::new(),
+            YarnSpinnerPlugin::with_yarn_source(YarnFileSource::file("dialogue/on_the_rocks.yarn"))
+                .with_localizations(Localizations {
+                    base_localization: Locale::BASE.code().into(),
+                    translations: Locale::ALL
+                        .iter()
+                        .filter(|locale| **locale != Locale::BASE)
+                        .map(|locale| locale.code().into())
+                        .collect(),
+                })
+                // Line IDs and `dialogue/de-DE.strings.csv` are maintained by hand.
+                .with_development_file_generation(DevelopmentFileGeneration::None),
+            DialogueViewPlugin,
+            DialogueHistoryPlugin,
         ))
         .init_state::<DialogueState>()
-        .add_systems(OnEnter(GameState::Dialogues), spawn_dialogue_runner);
+        .init_resource::<NightVariables>()
+        .add_systems(OnEnter(GameState::Dialogues), spawn_dialogue_runner)
+        .add_systems(OnExit(GameState::Dialogues), store_night_variables);
     }
 }
 
@@ -36,56 +96,152 @@ fn spawn_dialogue_runner(
     mut commands: Commands,
     project: Res<YarnProject>,
     dialogue_state: Res<State<DialogueState>>,
+    localization: Res<Localization>,
+    night_variables: Res<NightVariables>,
+    mut launch_options: ResMut<LaunchOptions>,
 ) {
     let mut dialogue_runner = project.create_dialogue_runner(&mut commands);
+    dialogue_runner.set_language(localization.locale.code());
+    for (name, value) in night_variables.0.iter() {
+        if let Err(err) = dialogue_runner
+            .variable_storage_mut()
+            .set(name.clone(), value.clone())
+        {
+            warn!("Failed to restore Yarn variable {}: {}", name, err);
+        }
+    }
+    // `yarn_lint::COMMANDS` checks the arguments scripts pass to these.
     dialogue_runner
         .commands_mut()
         .add_command(
-            "change_gamestate",
+            yarn_commands::CHANGE_GAMESTATE,
             commands.register_system(change_gamestate),
         )
         .add_command(
-            "change_dialog_state",
+            yarn_commands::CHANGE_DIALOG_STATE,
             commands.register_system(change_dialog_state),
         )
-        .add_command("consume_drink", commands.register_system(consume_drink));
-
-    // Choose starting node based on dialogue state
-    let node = match dialogue_state.get() {
-        DialogueState::BartenderMonologue => "BartenderMonologue",
-        DialogueState::CarlEnters => "CarlEnters",
-        DialogueState::ZaraEnters => "ZaraEnters",
-        DialogueState::CodaEnters => "CodaEnters",
-        DialogueState::MysteryEnters => "MysteryEnters",
-    };
+        .add_command(
+            yarn_commands::CONSUME_DRINK,
+            commands.register_system(consume_drink),
+        )
+        .add_command(
+            yarn_commands::EXPRESSION,
+            commands.register_system(set_expression),
+        );
 
-    dialogue_runner.start_node(node);
+    // Choose starting node based on dialogue state, unless launched into a specific one
+    match launch_options.node.take() {
+        Some(node) => dialogue_runner.start_node(node),
+        None => dialogue_runner.start_node(dialogue_state.get().node_name()),
+    };
     commands.spawn((dialogue_runner, OnCustomerScreen));
 }
 
+fn store_night_variables(
+    runner_query: Query<&DialogueRunner>,
+    mut night_variables: ResMut<NightVariables>,
+) {
+    for runner in runner_query.iter() {
+        night_variables.0 = runner.variable_storage().variables();
+    }
+}
+
 fn change_gamestate(In(state): In<String>, mut game_state: ResMut<NextState<GameState>>) {
     info!("Changing game state to: {}", state);
-    match state.as_str() {
-        "Crafting" => game_state.set(GameState::Crafting),
-        "End" => game_state.set(GameState::EndNight),
-        "Menu" => game_state.set(GameState::MainMenu),
-        _ => println!("Unknown game state: {}", state),
+    match game_state_for(&state) {
+        Some(next) => game_state.set(next),
+        None => println!("Unknown game state: {}", state),
+    }
+}
+
+/// Maps a `change_gamestate` argument to its state.
+fn game_state_for(arg: &str) -> Option<GameState> {
+    match arg {
+        yarn_commands::GAME_STATE_CRAFTING => Some(GameState::Crafting),
+        yarn_commands::GAME_STATE_END => Some(GameState::EndNight),
+        yarn_commands::GAME_STATE_MENU => Some(GameState::MainMenu),
+        _ => None,
     }
 }
 
 fn change_dialog_state(In(state): In<String>, mut dialog_state: ResMut<NextState<DialogueState>>) {
     info!("Changing dialogue state to: {}", state);
-    match state.as_str() {
-        "Carl" => dialog_state.set(DialogueState::CarlEnters),
-        "Zara" => dialog_state.set(DialogueState::ZaraEnters),
-        "Coda" => dialog_state.set(DialogueState::CodaEnters),
-        "Mystery" => dialog_state.set(DialogueState::MysteryEnters),
-        _ => println!("Unknown dialogue state: {}", state),
+    match dialogue_state_for(&state) {
+        Some(next) => dialog_state.set(next),
+        None => println!("Unknown dialogue state: {}", state),
     }
 }
 
-fn consume_drink(drink_sprite_query: Query<Entity, With<DrinkSprite>>, mut commands: Commands) {
-    for entity in drink_sprite_query.iter() {
+/// Maps a `change_dialog_state` argument to the patron entering next.
+fn dialogue_state_for(arg: &str) -> Option<DialogueState> {
+    match arg {
+        yarn_commands::DIALOGUE_STATE_CARL => Some(DialogueState::CarlEnters),
+        yarn_commands::DIALOGUE_STATE_ZARA => Some(DialogueState::ZaraEnters),
+        yarn_commands::DIALOGUE_STATE_CODA => Some(DialogueState::CodaEnters),
+        yarn_commands::DIALOGUE_STATE_MYSTERY => Some(DialogueState::MysteryEnters),
+        _ => None,
+    }
+}
+
+/// Star grade of the last drink served, for scripts that react to quality.
+const DRINK_STARS_VARIABLE: &str = "$drink_stars";
+
+/// Hands the served drink's effect strength and grade to the dialogue before it is cleared away.
+fn consume_drink(
+    drink_sprite_query: Query<(Entity, Option<&Drink>), With<DrinkSprite>>,
+    mut runner_query: Query<&mut DialogueRunner>,
+    mut customer_query: Query<&mut Customer>,
+    mut commands: Commands,
+) {
+    for (entity, drink) in drink_sprite_query.iter() {
+        if let Some(drink) = drink {
+            // The bartender mixes, the patron drinks.
+            for mut customer in customer_query
+                .iter_mut()
+                .filter(|customer| customer.base_personality != Personality::Artificial)
+            {
+                customer.taste_drink(drink);
+            }
+            let variables = [
+                (
+                    drink.effect.primary_effect.yarn_variable(),
+                    drink.effect.strength,
+                ),
+                (DRINK_STARS_VARIABLE, drink.quality.stars() as f32),
+            ];
+            for mut runner in runner_query.iter_mut() {
+                for (variable, value) in variables {
+                    if let Err(err) = runner
+                        .variable_storage_mut()
+                        .set(variable.to_string(), YarnValue::Number(value))
+                    {
+                        warn!("Failed to set {}: {}", variable, err);
+                    }
+                }
+            }
+        }
         commands.entity(entity).despawn();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_linted_game_state_is_accepted() {
+        for arg in yarn_commands::GAME_STATE_ARGS {
+            assert!(game_state_for(arg).is_some(), "{arg} is not handled");
+        }
+        assert_eq!(game_state_for("Dialogues"), None);
+    }
+
+    #[test]
+    fn every_linted_dialogue_state_is_accepted() {
+        for arg in yarn_commands::DIALOGUE_STATE_ARGS {
+            assert!(dialogue_state_for(arg).is_some(), "{arg} is not handled");
+        }
+        assert_eq!(dialogue_state_for("Bartender"), None);
+    }
+}
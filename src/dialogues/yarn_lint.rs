@@ -0,0 +1,278 @@
+//! Compiles Yarn scripts with the Yarn Spinner compiler, then checks the
+//! program against what the game registers: jump targets, custom commands and
+//! their arguments, and the variables the engine writes.
+//!
+//! Kept free of Bevy and the game's own modules so the `yarn-lint` binary and
+//! the `yarn_lint` test can include it with `#[path]` and run without an app.
+
+use std::{collections::HashSet, fmt, fs, io, path::Path};
+
+use yarnspinner::{
+    compiler::{Compilation, Compiler, DiagnosticSeverity, File},
+    core::{Instruction, Position},
+};
+use yarnspinner_core::prelude::OpCode;
+
+#[path = "commands.rs"]
+mod commands;
+
+/// What a command argument may be.
+pub enum Arg {
+    OneOf(&'static [&'static str]),
+    Any,
+}
+
+pub struct CommandSpec {
+    pub name: &'static str,
+    pub args: &'static [Arg],
+}
+
+/// Custom commands added to every dialogue runner.
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: commands::CHANGE_GAMESTATE,
+        args: &[Arg::OneOf(commands::GAME_STATE_ARGS)],
+    },
+    CommandSpec {
+        name: commands::CHANGE_DIALOG_STATE,
+        args: &[Arg::OneOf(commands::DIALOGUE_STATE_ARGS)],
+    },
+    CommandSpec {
+        name: commands::CONSUME_DRINK,
+        args: &[],
+    },
+    CommandSpec {
+        name: commands::EXPRESSION,
+        // Unknown expressions fall back to neutral until the art lands.
+        args: &[Arg::OneOf(&["Bartender", "Carl", "Zara", "Coda"]), Arg::Any],
+    },
+];
+
+/// Commands the Yarn Spinner runtime handles itself; the rest compile to
+/// instructions of their own.
+const BUILTIN_COMMANDS: &[&str] = &["wait"];
+
+/// Variables the game writes into the dialogue: drink effects and grade from
+/// `consume_drink`, and `--night` from the launch options.
+pub const ENGINE_VARIABLES: &[&str] = &[
+    "$calming_effect",
+    "$energizing_effect",
+    "$mind_enhancing_effect",
+    "$courage_effect",
+    "$truth_effect",
+    "$healing_effect",
+    "$drink_stars",
+    "$night",
+];
+
+/// Nodes a `DialogueState` starts the runner at.
+pub const START_NODES: &[&str] = &[
+    "BartenderMonologue",
+    "CarlEnters",
+    "ZaraEnters",
+    "CodaEnters",
+    "MysteryEnters",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub file: String,
+    /// 1-based; 0 for problems with the project as a whole.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.file, self.line, self.message)
+    }
+}
+
+/// Every `.yarn` file directly in `dir`, as `(file name, source)`, sorted by name.
+pub fn read_yarn_files(dir: &Path) -> io::Result<Vec<(String, String)>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "yarn")
+        {
+            let name = path.display().to_string();
+            files.push((name, fs::read_to_string(&path)?));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Lints the files together, since jumps and declarations cross files.
+pub fn lint(files: &[(String, String)]) -> Vec<Diagnostic> {
+    let compilation = Compiler::new()
+        .add_files(files.iter().map(|(file_name, source)| File {
+            file_name: file_name.clone(),
+            source: source.clone(),
+        }))
+        .compile();
+    match compilation {
+        Ok(compilation) => check_program(&compilation),
+        Err(error) => error
+            .0
+            .into_iter()
+            .filter(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error)
+            .map(|diagnostic| Diagnostic {
+                file: diagnostic.file_name.unwrap_or_else(|| PROJECT.to_string()),
+                line: diagnostic.range.map_or(0, |range| range.start.line + 1),
+                message: diagnostic.message,
+            })
+            .collect(),
+    }
+}
+
+/// Stands in for a file name when a problem is with the scripts as a whole.
+const PROJECT: &str = "<project>";
+
+fn check_program(compilation: &Compilation) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let Some(program) = &compilation.program else {
+        return diagnostics;
+    };
+    let mut report = |file: &str, line: usize, message: String| {
+        diagnostics.push(Diagnostic {
+            file: file.to_string(),
+            line,
+            message,
+        })
+    };
+
+    let declared: HashSet<&str> = compilation
+        .declarations
+        .iter()
+        .filter(|declaration| !declaration.is_implicit)
+        .map(|declaration| declaration.name.as_str())
+        .collect();
+    let mut assigned = HashSet::new();
+    let mut read = Vec::new();
+    for (node_name, node) in program.nodes.iter() {
+        let debug_info = compilation.debug_info.get(node_name);
+        let file = debug_info.map_or(PROJECT, |info| info.file_name.as_str());
+        let line_of = |index: usize| {
+            debug_info
+                .and_then(|info| info.try_get_line_info(index))
+                .and_then(|info| info.position)
+                .map_or(0, |position: Position| position.line + 1)
+        };
+        for (index, instruction) in node.instructions.iter().enumerate() {
+            let line = line_of(index);
+            match instruction.opcode() {
+                OpCode::RunCommand => {
+                    for message in check_command(&string_operand(instruction)) {
+                        report(file, line, message);
+                    }
+                }
+                // `<<jump>>` pushes its target just before running it.
+                OpCode::RunNode => {
+                    let target = index
+                        .checked_sub(1)
+                        .map(|previous| &node.instructions[previous])
+                        .filter(|previous| previous.opcode() == OpCode::PushString)
+                        .map(string_operand);
+                    if let Some(target) = target
+                        && !program.nodes.contains_key(&target)
+                    {
+                        report(file, line, format!("jump target {target} does not exist"));
+                    }
+                }
+                OpCode::StoreVariable => {
+                    assigned.insert(string_operand(instruction));
+                }
+                OpCode::PushVariable => {
+                    read.push((string_operand(instruction), file, line));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for (variable, file, line) in read {
+        // Visit tracking reads variables the compiler generates itself.
+        if variable.starts_with("$Yarn.Internal") {
+            continue;
+        }
+        if !declared.contains(variable.as_str()) && !assigned.contains(&variable) {
+            report(file, line, format!("{variable} is never declared or set"));
+        }
+    }
+    for variable in ENGINE_VARIABLES {
+        if !declared.contains(variable) {
+            report(
+                PROJECT,
+                0,
+                format!("{variable} is set by the game but never declared"),
+            );
+        }
+    }
+    for node in START_NODES {
+        if !program.nodes.contains_key(*node) {
+            report(PROJECT, 0, format!("start node {node} does not exist"));
+        }
+    }
+    diagnostics
+}
+
+fn string_operand(instruction: &Instruction) -> String {
+    instruction.read_operand(0)
+}
+
+/// Problems with one command, from its text as the compiler emitted it.
+fn check_command(text: &str) -> Vec<String> {
+    let words = split_args(text);
+    let Some((name, args)) = words.split_first() else {
+        return vec!["empty command".to_string()];
+    };
+    if BUILTIN_COMMANDS.contains(&name.as_str()) {
+        return Vec::new();
+    }
+    let Some(spec) = COMMANDS.iter().find(|spec| spec.name == name) else {
+        return vec![format!("<<{name}>> is not a registered command")];
+    };
+    let mut problems = Vec::new();
+    if args.len() != spec.args.len() {
+        problems.push(format!(
+            "<<{name}>> takes {} argument(s), got {}",
+            spec.args.len(),
+            args.len()
+        ));
+    }
+    for (arg, expected) in args.iter().zip(spec.args) {
+        match expected {
+            Arg::OneOf(allowed) if !allowed.contains(&arg.as_str()) => problems.push(format!(
+                "<<{name}>> does not accept \"{arg}\", expected one of {}",
+                allowed.join(", ")
+            )),
+            _ => {}
+        }
+    }
+    problems
+}
+
+/// Splits command text on whitespace, keeping quoted arguments whole and unquoted.
+fn split_args(command: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for character in command.chars() {
+        match character {
+            '"' => quoted = !quoted,
+            character if character.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            character => current.push(character),
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
@@ -1,7 +1,9 @@
-use bevy::prelude::*;
+use bevy::{asset::UntypedAssetLoadFailedEvent, prelude::*};
 use bevy_asset_loader::prelude::*;
 use bevy_seedling::sample::Sample;
 
+use crate::animation::sprite_animation::SpriteAnimation;
+
 #[derive(AssetCollection, Resource)]
 pub struct ImageAssets {
     #[asset(path = "images/characters/bartender.png")]
@@ -70,6 +72,14 @@ pub struct ImageAssets {
     pub talk_background: Handle<Image>,
 }
 
+#[derive(AssetCollection, Resource)]
+pub struct AnimationAssets {
+    #[asset(path = "animations/bartender.anim.ron")]
+    pub bartender: Handle<SpriteAnimation>,
+    #[asset(path = "animations/icegel.anim.ron")]
+    pub icegel: Handle<SpriteAnimation>,
+}
+
 #[derive(AssetCollection, Resource)]
 pub struct AudioAssets {
     #[asset(path = "audio/Ketsa - Drifting Space Jazz.ogg")]
@@ -77,3 +87,54 @@ pub struct AudioAssets {
     #[asset(path = "audio/HoliznaCC0 - Space!.ogg")]
     pub background2: Handle<Sample>,
 }
+
+/// Asset paths that failed to load, shown on the loading error screen.
+#[derive(Resource, Default, Debug)]
+pub struct LoadFailures(pub Vec<String>);
+
+/// Logs manifest paths that would fail to load on a case-sensitive file system,
+/// before the loading screen runs into them.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn check_asset_manifest() {
+    use bevy::asset::io::file::FileAssetReader;
+
+    use crate::engine::asset_manifest::{check, manifest_paths};
+
+    let assets_dir = FileAssetReader::get_base_path().join("assets");
+    let report = match check(&assets_dir, &manifest_paths()) {
+        Ok(report) => report,
+        Err(err) => {
+            warn!(
+                "Could not check assets in {}: {}",
+                assets_dir.display(),
+                err
+            );
+            return;
+        }
+    };
+    for path in report.missing.iter() {
+        error!("Asset {} is missing", path);
+    }
+    for (path, file) in report.wrong_case.iter() {
+        error!(
+            "Asset {} only exists as {}, which fails on case-sensitive file systems",
+            path, file
+        );
+    }
+    for path in report.unused.iter() {
+        warn!("Asset {} is not used by the game", path);
+    }
+}
+
+pub fn record_load_failures(
+    mut failed_events: EventReader<UntypedAssetLoadFailedEvent>,
+    mut failures: ResMut<LoadFailures>,
+) {
+    for event in failed_events.read() {
+        let path = event.path.to_string();
+        error!("Failed to load asset {}: {}", path, event.error);
+        if !failures.0.contains(&path) {
+            failures.0.push(path);
+        }
+    }
+}
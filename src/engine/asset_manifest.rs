@@ -0,0 +1,85 @@
+//! Every file the game loads from `assets/`, checked against what is on disk.
+//!
+//! Paths must match exactly: Windows and macOS forgive a wrong case, Linux and
+//! most web hosts don't. Free of Bevy so the `asset_manifest` test can include
+//! it with `#[path]`.
+
+use std::{
+    collections::BTreeSet,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The collections' `#[asset(path = ...)]` attributes are read from here.
+const ASSET_LOADER_SOURCE: &str = include_str!("asset_loader.rs");
+
+/// Files loaded outside the asset collections.
+pub const DIRECT_LOADS: &[&str] = &[
+    "fonts/Nasa21.ttf",
+    "images/ui/name.png",
+    "dialogue/on_the_rocks.yarn",
+    // Loaded by the dialogue runner when the language is set to German.
+    "dialogue/de-DE.strings.csv",
+    // Compiled in with `include_str!`, but they live with the other assets.
+    "locales/en-US.strings",
+    "locales/de-DE.strings",
+];
+
+/// Every path the game asks for, relative to `assets/`.
+pub fn manifest_paths() -> Vec<&'static str> {
+    const ATTRIBUTE: &str = "#[asset(path = \"";
+    let mut paths: Vec<&'static str> = ASSET_LOADER_SOURCE
+        .match_indices(ATTRIBUTE)
+        .filter_map(|(index, _)| {
+            let rest = &ASSET_LOADER_SOURCE[index + ATTRIBUTE.len()..];
+            rest.find('"').map(|end| &rest[..end])
+        })
+        .collect();
+    paths.extend_from_slice(DIRECT_LOADS);
+    paths
+}
+
+#[derive(Debug, Default)]
+pub struct ManifestReport {
+    pub missing: Vec<String>,
+    /// Requested path and the file that only matches it ignoring case.
+    pub wrong_case: Vec<(String, String)>,
+    /// Files on disk nothing asks for. Only bloat, unlike the two above.
+    pub unused: Vec<String>,
+}
+
+pub fn check(assets_dir: &Path, paths: &[&str]) -> io::Result<ManifestReport> {
+    let mut on_disk = BTreeSet::new();
+    collect_files(assets_dir, assets_dir, &mut on_disk)?;
+    let mut report = ManifestReport::default();
+    let mut matched = BTreeSet::new();
+    for path in paths {
+        if on_disk.contains(*path) {
+            matched.insert(path.to_string());
+        } else if let Some(file) = on_disk.iter().find(|file| file.eq_ignore_ascii_case(path)) {
+            matched.insert(file.clone());
+            report.wrong_case.push((path.to_string(), file.clone()));
+        } else {
+            report.missing.push(path.to_string());
+        }
+    }
+    report.unused = on_disk.difference(&matched).cloned().collect();
+    Ok(report)
+}
+
+/// Files under `dir` as `/`-separated paths relative to `root`.
+fn collect_files(root: &Path, dir: &Path, files: &mut BTreeSet<String>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path: PathBuf = entry?.path();
+        if path.is_dir() {
+            collect_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            let parts: Vec<String> = relative
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            files.insert(parts.join("/"));
+        }
+    }
+    Ok(())
+}
@@ -2,19 +2,44 @@ use bevy::prelude::*;
 use bevy_seedling::prelude::*;
 
 use crate::{
-    bar::crafting::OnCraftingScreen, customers::OnCustomerScreen, engine::asset_loader::AudioAssets,
+    bar::crafting::OnCraftingScreen,
+    customers::OnCustomerScreen,
+    engine::{asset_loader::AudioAssets, settings::GameSettings},
 };
 
 pub struct AudioControllerPlugin;
 
 impl Plugin for AudioControllerPlugin {
-    fn build(&self, _app: &mut App) {
-        todo!()
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_audio_buses).add_systems(
+            Update,
+            apply_volume_settings
+                .run_if(resource_changed::<GameSettings>.or(any_match_filter::<Added<VolumeNode>>)),
+        );
     }
 }
 
+/// Background tracks, with their own volume.
+#[derive(PoolLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MusicPool;
+
+/// Volume node everything outside [`MusicPool`] plays through.
+#[derive(NodeLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SoundEffectsBus;
+
+/// Replaces seedling's default pool, which `GameRunnerPlugin` turns off, with
+/// one routed through [`SoundEffectsBus`] so the SFX volume applies to it.
+fn spawn_audio_buses(mut commands: Commands) {
+    commands.spawn((SoundEffectsBus, VolumeNode::default()));
+    commands
+        .spawn(SamplerPool(DefaultPool))
+        .connect(SoundEffectsBus);
+    commands.spawn(SamplerPool(MusicPool));
+}
+
 pub fn play_customer_bg(mut commands: Commands, audio_assets: Res<AudioAssets>) {
     commands.spawn((
+        MusicPool,
         SamplePlayer::new(audio_assets.background.clone()).looping(),
         OnCustomerScreen,
     ));
@@ -22,7 +47,31 @@ pub fn play_customer_bg(mut commands: Commands, audio_assets: Res<AudioAssets>)
 
 pub fn play_crafting_bg(mut commands: Commands, audio_assets: Res<AudioAssets>) {
     commands.spawn((
+        MusicPool,
         SamplePlayer::new(audio_assets.background2.clone()).looping(),
         OnCraftingScreen,
     ));
 }
+
+type SoundEffectsBusOnly = (
+    With<SoundEffectsBus>,
+    Without<MainBus>,
+    Without<SamplerPool<MusicPool>>,
+);
+
+fn apply_volume_settings(
+    settings: Res<GameSettings>,
+    mut master_query: Query<&mut VolumeNode, With<MainBus>>,
+    mut music_query: Query<&mut VolumeNode, (With<SamplerPool<MusicPool>>, Without<MainBus>)>,
+    mut sfx_query: Query<&mut VolumeNode, SoundEffectsBusOnly>,
+) {
+    for mut node in master_query.iter_mut() {
+        node.volume = Volume::Linear(settings.master_volume);
+    }
+    for mut node in music_query.iter_mut() {
+        node.volume = Volume::Linear(settings.music_volume);
+    }
+    for mut node in sfx_query.iter_mut() {
+        node.volume = Volume::Linear(settings.sfx_volume);
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use bevy::{
+    input::InputSystem,
+    prelude::*,
+    ui::{FocusPolicy, UiSystem},
+};
+
+use crate::{
+    animation::tween::Ease,
+    engine::{GameState, game_runner::MainGameCamera},
+};
+
+pub struct CameraTransitionPlugin;
+
+impl Plugin for CameraTransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TransitionSettings>()
+            .add_systems(
+                PreUpdate,
+                block_input_during_transition
+                    .after(InputSystem)
+                    .before(UiSystem::Focus)
+                    .run_if(resource_exists::<ActiveTransition>),
+            )
+            .add_systems(Update, (start_transition, run_transition).chain());
+    }
+}
+
+/// How the view arrives in a new state. The new scene is already spawned; the
+/// transition eases the camera or an overlay from its starting point to rest.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CameraTransition {
+    Cut,
+    /// Black overlay fading out.
+    Fade {
+        seconds: f32,
+    },
+    /// Camera slides from `from` back to the origin.
+    Pan {
+        from: Vec2,
+        seconds: f32,
+    },
+    /// Camera scale eases from `from` back to 1.
+    Zoom {
+        from: f32,
+        seconds: f32,
+    },
+}
+
+impl CameraTransition {
+    fn seconds(&self) -> f32 {
+        match self {
+            CameraTransition::Cut => 0.0,
+            CameraTransition::Fade { seconds }
+            | CameraTransition::Pan { seconds, .. }
+            | CameraTransition::Zoom { seconds, .. } => *seconds,
+        }
+    }
+}
+
+/// Transition per `(from, to)` state pair; pairs without an entry cut.
+#[derive(Resource, Clone, Debug)]
+pub struct TransitionSettings {
+    pub transitions: HashMap<(GameState, GameState), CameraTransition>,
+}
+
+impl Default for TransitionSettings {
+    fn default() -> Self {
+        Self {
+            transitions: HashMap::new(),
+        }
+        // Down from the patrons to the counter, and back up again.
+        .with(
+            GameState::Dialogues,
+            GameState::Crafting,
+            CameraTransition::Pan {
+                from: Vec2::new(0.0, 540.0),
+                seconds: 0.6,
+            },
+        )
+        .with(
+            GameState::Crafting,
+            GameState::Dialogues,
+            CameraTransition::Pan {
+                from: Vec2::new(0.0, -540.0),
+                seconds: 0.6,
+            },
+        )
+        .with(
+            GameState::Loading,
+            GameState::Dialogues,
+            CameraTransition::Fade { seconds: 0.8 },
+        )
+        .with(
+            GameState::Dialogues,
+            GameState::EndNight,
+            CameraTransition::Zoom {
+                from: 0.7,
+                seconds: 1.2,
+            },
+        )
+    }
+}
+
+impl TransitionSettings {
+    pub fn with(mut self, from: GameState, to: GameState, transition: CameraTransition) -> Self {
+        self.transitions.insert((from, to), transition);
+        self
+    }
+
+    pub fn get(&self, from: &GameState, to: &GameState) -> CameraTransition {
+        self.transitions
+            .get(&(from.clone(), to.clone()))
+            .copied()
+            .unwrap_or(CameraTransition::Cut)
+    }
+}
+
+/// Present while a transition runs; input is swallowed until it is gone.
+#[derive(Resource, Debug)]
+pub struct ActiveTransition {
+    pub transition: CameraTransition,
+    pub timer: Timer,
+}
+
+/// Full-screen layer that blocks the pointer and, for fades, draws the black.
+#[derive(Component)]
+struct TransitionOverlay;
+
+fn start_transition(
+    mut commands: Commands,
+    mut transitions: EventReader<StateTransitionEvent<GameState>>,
+    settings: Res<TransitionSettings>,
+    overlay_query: Query<Entity, With<TransitionOverlay>>,
+) {
+    let Some(transition) = transitions
+        .read()
+        .filter_map(|event| match (&event.exited, &event.entered) {
+            (Some(from), Some(to)) if from != to => Some(settings.get(from, to)),
+            _ => None,
+        })
+        .last()
+    else {
+        return;
+    };
+    for entity in overlay_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    if transition == CameraTransition::Cut {
+        commands.remove_resource::<ActiveTransition>();
+        return;
+    }
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        FocusPolicy::Block,
+        GlobalZIndex(100),
+        TransitionOverlay,
+    ));
+    commands.insert_resource(ActiveTransition {
+        transition,
+        timer: Timer::from_seconds(transition.seconds(), TimerMode::Once),
+    });
+}
+
+fn run_transition(
+    mut commands: Commands,
+    time: Res<Time>,
+    transition: Option<ResMut<ActiveTransition>>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<MainGameCamera>>,
+    mut overlay_query: Query<(Entity, &mut BackgroundColor), With<TransitionOverlay>>,
+) {
+    let Some(mut transition) = transition else {
+        return;
+    };
+    transition.timer.tick(time.delta());
+    let remaining = 1.0 - Ease::CubicInOut.apply(transition.timer.fraction());
+
+    for (mut transform, mut projection) in camera_query.iter_mut() {
+        let (offset, scale) = match transition.transition {
+            CameraTransition::Pan { from, .. } => (from * remaining, 1.0),
+            CameraTransition::Zoom { from, .. } => (Vec2::ZERO, 1.0 + (from - 1.0) * remaining),
+            CameraTransition::Cut | CameraTransition::Fade { .. } => (Vec2::ZERO, 1.0),
+        };
+        transform.translation.x = offset.x;
+        transform.translation.y = offset.y;
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scale = scale;
+        }
+    }
+    for (_, mut background) in overlay_query.iter_mut() {
+        if let CameraTransition::Fade { .. } = transition.transition {
+            background.0 = Color::BLACK.with_alpha(remaining);
+        }
+    }
+
+    if transition.timer.finished() {
+        for (entity, _) in overlay_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        commands.remove_resource::<ActiveTransition>();
+    }
+}
+
+/// Drops keyboard, mouse and gamepad presses so nothing reacts mid-transition.
+fn block_input_during_transition(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    mut gamepads: Query<&mut Gamepad>,
+) {
+    keys.reset_all();
+    mouse.reset_all();
+    for mut gamepad in gamepads.iter_mut() {
+        gamepad.digital_mut().reset_all();
+    }
+}
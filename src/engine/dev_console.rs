@@ -0,0 +1,605 @@
+use std::collections::HashMap;
+
+use bevy::{
+    ecs::system::SystemParam,
+    input::{
+        ButtonState, InputSystem,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+    ui::{FocusPolicy, UiSystem},
+};
+use bevy_yarnspinner::prelude::*;
+
+use crate::{
+    bar::{
+        drinks::{CreatedDrink, Drink, DrinkEffect, DrinkTaste, spawn_crafted_drink},
+        glass::Glass,
+        quality::DrinkQuality,
+        reactions::remix,
+        technique::Technique,
+        temperature::mix_temperature,
+    },
+    customers::Customer,
+    dialogues::{DialogueState, NightVariables},
+    engine::{
+        GameState,
+        asset_loader::ImageAssets,
+        launch::{parse_yarn_value, variable_name},
+    },
+    ingredients::{Ingredient, IngredientTaste, PrimaryEffect, ShelfSlot},
+    ui::widgets::{LabelSize, UiTheme},
+};
+
+const TOGGLE_KEY: KeyCode = KeyCode::Backquote;
+/// Output lines kept, and how many of the newest are shown.
+const MAX_OUTPUT: usize = 200;
+const VISIBLE_OUTPUT: usize = 14;
+const DEFAULT_STRENGTH: f32 = 10.0;
+
+const HELP: &[&str] = &[
+    "state <MainMenu|Dialogues|Crafting|EndNight>",
+    "dialogue <BartenderMonologue|CarlEnters|ZaraEnters|CodaEnters>",
+    "node <Node>             start a Yarn node",
+    "get [$variable]         print one or every Yarn variable",
+    "set <$variable> <value> number, true/false, or text",
+    "drink <Drink> [Effect] [strength]   serve a perfect drink",
+    "fill <slot|Ingredient> [volume]     pour any amount, up to full",
+    "customers               print the patrons on screen",
+    "clear",
+];
+
+const GAME_STATES: [GameState; 5] = [
+    GameState::MainMenu,
+    GameState::Loading,
+    GameState::Dialogues,
+    GameState::Crafting,
+    GameState::EndNight,
+];
+
+const CREATED_DRINKS: [CreatedDrink; 10] = [
+    CreatedDrink::ZeroPhase,
+    CreatedDrink::CryoDrop,
+    CreatedDrink::StellarLumen,
+    CreatedDrink::Cosmopolitan,
+    CreatedDrink::SynthCascade,
+    CreatedDrink::OldMemory,
+    CreatedDrink::EchoBloom,
+    CreatedDrink::BotanicalSurge,
+    CreatedDrink::BinaryBarrel,
+    CreatedDrink::EventHorizon,
+];
+
+const PRIMARY_EFFECTS: [PrimaryEffect; 6] = [
+    PrimaryEffect::Calming,
+    PrimaryEffect::Energizing,
+    PrimaryEffect::MindEnhancing,
+    PrimaryEffect::CourageBoosting,
+    PrimaryEffect::TruthInducing,
+    PrimaryEffect::Healing,
+];
+
+/// In-game command line for jumping around the night while testing. Only built
+/// with the `dev` feature.
+pub struct DevConsolePlugin;
+
+impl Plugin for DevConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DevConsole>()
+            .add_systems(
+                PreUpdate,
+                block_game_keys
+                    .after(InputSystem)
+                    .before(UiSystem::Focus)
+                    .run_if(|console: Res<DevConsole>| console.open),
+            )
+            .add_systems(
+                Update,
+                (read_console_keys, run_console_commands, refresh_console).chain(),
+            );
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct DevConsole {
+    pub open: bool,
+    input: String,
+    output: Vec<String>,
+    history: Vec<String>,
+    /// How far back through `history` the up arrow has gone.
+    recalled: usize,
+    pending: Vec<String>,
+}
+
+impl DevConsole {
+    fn print(&mut self, line: impl Into<String>) {
+        let line = line.into();
+        info!("[console] {}", line);
+        self.output.push(line);
+        if self.output.len() > MAX_OUTPUT {
+            self.output.drain(..self.output.len() - MAX_OUTPUT);
+        }
+    }
+}
+
+#[derive(Component)]
+struct ConsolePanel;
+
+#[derive(Debug)]
+enum ConsoleCommand {
+    Help,
+    Clear,
+    State(GameState),
+    Dialogue(DialogueState),
+    Node(String),
+    Get(Option<String>),
+    Set(String, YarnValue),
+    Drink(CreatedDrink, PrimaryEffect, f32),
+    Fill(String, Option<f32>),
+    Customers,
+}
+
+fn find_by_name<T: Copy + std::fmt::Debug>(options: &[T], name: &str) -> Result<T, String> {
+    options
+        .iter()
+        .find(|option| format!("{option:?}").eq_ignore_ascii_case(name))
+        .copied()
+        .ok_or_else(|| {
+            let names: Vec<String> = options.iter().map(|option| format!("{option:?}")).collect();
+            format!("Unknown {name}, expected one of {}", names.join(", "))
+        })
+}
+
+fn parse_number(value: &str) -> Result<f32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Expected a number, got {value}"))
+}
+
+fn parse_command(line: &str) -> Result<ConsoleCommand, String> {
+    let mut words = line.split_whitespace();
+    let Some(name) = words.next() else {
+        return Err("Empty command".to_string());
+    };
+    let args: Vec<&str> = words.collect();
+    let arg = |index: usize| {
+        args.get(index)
+            .copied()
+            .ok_or_else(|| format!("{name} needs more arguments, see help"))
+    };
+    Ok(match name {
+        "help" => ConsoleCommand::Help,
+        "clear" => ConsoleCommand::Clear,
+        "state" => {
+            let state = arg(0)?;
+            ConsoleCommand::State(
+                GAME_STATES
+                    .iter()
+                    .find(|option| format!("{option:?}").eq_ignore_ascii_case(state))
+                    .cloned()
+                    .ok_or_else(|| format!("Unknown game state {state}"))?,
+            )
+        }
+        "dialogue" => {
+            let state = arg(0)?;
+            let dialogue = DialogueState::from_node_name(state)
+                .ok_or_else(|| format!("Unknown dialogue state {state}"))?;
+            if !dialogue.is_playable() {
+                return Err(format!("{state} has no patron to show yet"));
+            }
+            ConsoleCommand::Dialogue(dialogue)
+        }
+        "node" => ConsoleCommand::Node(arg(0)?.to_string()),
+        "get" => ConsoleCommand::Get(args.first().map(|name| variable_name(name))),
+        "set" => {
+            let name = variable_name(arg(0)?);
+            let value = args
+                .get(1..)
+                .map(|rest| rest.join(" "))
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| "set needs a value".to_string())?;
+            ConsoleCommand::Set(name, parse_yarn_value(&value))
+        }
+        "drink" => ConsoleCommand::Drink(
+            find_by_name(&CREATED_DRINKS, arg(0)?)?,
+            match args.get(1) {
+                Some(effect) => find_by_name(&PRIMARY_EFFECTS, effect)?,
+                None => PrimaryEffect::Calming,
+            },
+            match args.get(2) {
+                Some(strength) => parse_number(strength)?,
+                None => DEFAULT_STRENGTH,
+            },
+        ),
+        "fill" => ConsoleCommand::Fill(
+            arg(0)?.to_string(),
+            args.get(1).map(|volume| parse_number(volume)).transpose()?,
+        ),
+        "customers" => ConsoleCommand::Customers,
+        other => return Err(format!("Unknown command {other}, try help")),
+    })
+}
+
+/// A drink made exactly to recipe, as if it had just come off the counter.
+fn perfect_drink(
+    created_drink: CreatedDrink,
+    primary_effect: PrimaryEffect,
+    strength: f32,
+) -> Drink {
+    let tastes = created_drink.ideal_tastes();
+    let taste = |index: usize| {
+        tastes
+            .get(index)
+            .map(|(taste, _)| *taste)
+            .unwrap_or(IngredientTaste::None)
+    };
+    Drink {
+        name: format!("{:#?}", created_drink),
+        ingredients: HashMap::new(),
+        taste: DrinkTaste {
+            primary_taste: taste(0),
+            secondary_taste: taste(1),
+        },
+        effect: DrinkEffect {
+            primary_effect,
+            secondary_effect: primary_effect,
+            strength,
+        },
+        created_drink,
+        technique: Technique::default(),
+        technique_quality: 1.0,
+        temperature: created_drink.ideal_temperature(),
+        quality: DrinkQuality {
+            fill: 1.0,
+            balance: 1.0,
+            technique: 1.0,
+            temperature: 1.0,
+        },
+        secondary_effects: Vec::new(),
+        reactions: Vec::new(),
+    }
+}
+
+/// Everything console commands can reach into.
+#[derive(SystemParam)]
+struct ConsoleTargets<'w, 's> {
+    commands: Commands<'w, 's>,
+    game_state: ResMut<'w, NextState<GameState>>,
+    dialogue_state: ResMut<'w, NextState<DialogueState>>,
+    night_variables: ResMut<'w, NightVariables>,
+    image_assets: Option<Res<'w, ImageAssets>>,
+    runners: Query<'w, 's, &'static mut DialogueRunner>,
+    glasses: Query<'w, 's, &'static mut Glass>,
+    ingredients: Query<'w, 's, &'static Ingredient>,
+    slots: Query<'w, 's, (Entity, &'static Ingredient, &'static ShelfSlot)>,
+    customers: Query<'w, 's, &'static Customer>,
+}
+
+impl ConsoleTargets<'_, '_> {
+    fn run(&mut self, command: ConsoleCommand, console: &mut DevConsole) -> Result<(), String> {
+        match command {
+            ConsoleCommand::Help => {
+                for line in HELP {
+                    console.print(*line);
+                }
+            }
+            ConsoleCommand::Clear => console.output.clear(),
+            ConsoleCommand::State(state) => {
+                console.print(format!("Game state -> {state:?}"));
+                self.game_state.set(state);
+            }
+            ConsoleCommand::Dialogue(state) => {
+                // A running conversation follows along; otherwise the next visit starts there.
+                for mut runner in self.runners.iter_mut() {
+                    restart_at(&mut runner, state.node_name())?;
+                }
+                console.print(format!("Dialogue state -> {state:?}"));
+                self.dialogue_state.set(state);
+            }
+            ConsoleCommand::Node(node) => {
+                let mut started = false;
+                for mut runner in self.runners.iter_mut() {
+                    restart_at(&mut runner, &node)?;
+                    started = true;
+                }
+                if !started {
+                    return Err("No dialogue is running; switch to state Dialogues first".into());
+                }
+                console.print(format!("Started node {node}"));
+            }
+            ConsoleCommand::Get(name) => {
+                let mut variables = self.variables();
+                if let Some(name) = name {
+                    variables.retain(|variable, _| *variable == name);
+                    if variables.is_empty() {
+                        return Err(format!("{name} is not set"));
+                    }
+                }
+                let mut names: Vec<&String> = variables.keys().collect();
+                names.sort();
+                for name in names {
+                    console.print(format!("{name} = {:?}", variables[name]));
+                }
+            }
+            ConsoleCommand::Set(name, value) => {
+                let mut in_runner = false;
+                for mut runner in self.runners.iter_mut() {
+                    runner
+                        .variable_storage_mut()
+                        .set(name.clone(), value.clone())
+                        .map_err(|err| err.to_string())?;
+                    in_runner = true;
+                }
+                // Between conversations the night's variables wait here for the next runner.
+                if !in_runner {
+                    self.night_variables.0.insert(name.clone(), value.clone());
+                }
+                console.print(format!("{name} = {value:?}"));
+            }
+            ConsoleCommand::Drink(created_drink, effect, strength) => {
+                let Some(image_assets) = self.image_assets.as_ref() else {
+                    return Err("Assets are not loaded yet".into());
+                };
+                let drink = perfect_drink(created_drink, effect, strength);
+                spawn_crafted_drink(&mut self.commands, drink, image_assets);
+                console.print(format!(
+                    "Served {created_drink:?} ({effect:?} {strength:.1}); it is drunk at the next consume_drink"
+                ));
+            }
+            ConsoleCommand::Fill(which, volume) => {
+                let (entity, ingredient) = self
+                    .slots
+                    .iter()
+                    .find(|(_, ingredient, slot)| {
+                        which.parse::<usize>().ok() == Some(slot.0 + 1)
+                            || format!("{:?}", ingredient.id).eq_ignore_ascii_case(&which)
+                    })
+                    .map(|(entity, ingredient, _)| (entity, ingredient.clone()))
+                    .ok_or_else(|| format!("No ingredient {which} on the counter"))?;
+                let Some(mut glass) = self.glasses.iter_mut().next() else {
+                    return Err("No glass; switch to state Crafting first".into());
+                };
+                let current = glass.get_current_volume();
+                let volume = volume
+                    .unwrap_or(glass.capacity - current)
+                    .min(glass.capacity - current);
+                if volume <= 0.0 {
+                    return Err("The glass is already full".into());
+                }
+                glass.temperature = mix_temperature(
+                    glass.temperature,
+                    current,
+                    ingredient.ingredient_profile.temperature,
+                    volume,
+                );
+                glass.technique = None;
+                *glass.ingredients.entry(entity).or_insert(0.0) += volume;
+                let reactions = remix(&mut glass, &self.ingredients);
+                console.print(format!(
+                    "Poured {volume:.0} of {:?}, glass at {:.0}/{:.0}",
+                    ingredient.id,
+                    glass.get_current_volume(),
+                    glass.capacity
+                ));
+                for reaction in reactions {
+                    console.print(format!("Reaction: {reaction:?}"));
+                }
+            }
+            ConsoleCommand::Customers => {
+                if self.customers.is_empty() {
+                    console.print("No customers on screen");
+                }
+                for customer in self.customers.iter() {
+                    console.print(format!(
+                        "{}: {:?}, satisfaction {:.0}, likes {:?}, dislikes {:?}, prefers {:.1}°C, node {:?}, drink {:?}",
+                        customer.name,
+                        customer.base_personality,
+                        customer.satisfaction_score,
+                        customer.preferred_taste,
+                        customer.disliked_taste,
+                        customer.preferred_temperature,
+                        customer.dialogue_node,
+                        customer.current_drink,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn variables(&self) -> HashMap<String, YarnValue> {
+        self.runners
+            .iter()
+            .next()
+            .map(|runner| runner.variable_storage().variables())
+            .unwrap_or_else(|| self.night_variables.0.clone())
+    }
+}
+
+fn restart_at(runner: &mut DialogueRunner, node: &str) -> Result<(), String> {
+    if runner.is_running() {
+        runner.stop();
+    }
+    runner
+        .try_start_node(node)
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+/// Keeps typed text from also pouring, pausing or navigating.
+fn block_game_keys(mut keys: ResMut<ButtonInput<KeyCode>>) {
+    keys.reset_all();
+}
+
+fn read_console_keys(mut console: ResMut<DevConsole>, mut key_events: EventReader<KeyboardInput>) {
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        if event.key_code == TOGGLE_KEY {
+            if !event.repeat {
+                console.open = !console.open;
+            }
+            continue;
+        }
+        if !console.open {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                let line = line.trim().to_string();
+                if line.is_empty() {
+                    continue;
+                }
+                console.print(format!("> {line}"));
+                console.history.push(line.clone());
+                console.recalled = 0;
+                console.pending.push(line);
+            }
+            Key::Escape => console.open = false,
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::ArrowUp if console.recalled < console.history.len() => {
+                console.recalled += 1;
+                console.input = console.history[console.history.len() - console.recalled].clone();
+            }
+            Key::ArrowDown => {
+                console.recalled = console.recalled.saturating_sub(1);
+                console.input = match console.recalled {
+                    0 => String::new(),
+                    recalled => console.history[console.history.len() - recalled].clone(),
+                };
+            }
+            Key::Space => console.input.push(' '),
+            Key::Character(text) => console.input.push_str(text),
+            _ => {}
+        }
+    }
+}
+
+fn run_console_commands(mut console: ResMut<DevConsole>, mut targets: ConsoleTargets) {
+    if console.pending.is_empty() {
+        return;
+    }
+    for line in std::mem::take(&mut console.pending) {
+        if let Err(err) =
+            parse_command(&line).and_then(|command| targets.run(command, &mut console))
+        {
+            console.print(format!("error: {err}"));
+        }
+    }
+}
+
+fn refresh_console(
+    mut commands: Commands,
+    console: Res<DevConsole>,
+    theme: Res<UiTheme>,
+    panel_query: Query<Entity, With<ConsolePanel>>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    if !console.open {
+        return;
+    }
+    let start = console.output.len().saturating_sub(VISIBLE_OUTPUT);
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(0.0),
+            left: Val::Px(0.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(12.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.92)),
+        FocusPolicy::Block,
+        // Above pause menus and transition overlays.
+        GlobalZIndex(200),
+        ConsolePanel,
+        children![
+            (
+                Text::new(console.output[start..].join("\n")),
+                theme.text_font(LabelSize::Small),
+                TextColor(theme.text),
+            ),
+            (
+                Text::new(format!("> {}_", console.input)),
+                theme.text_font(LabelSize::Small),
+                TextColor(theme.text_light),
+            ),
+        ],
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Each match is exhaustive, so adding a variant fails to compile here until
+    // it is listed above or deliberately left out.
+
+    #[test]
+    fn game_states_list_every_reachable_state() {
+        let listed = |state: &GameState| match state {
+            GameState::MainMenu
+            | GameState::Loading
+            | GameState::Dialogues
+            | GameState::Crafting
+            | GameState::EndNight => true,
+            // Only reached through a failed load.
+            GameState::LoadingFailed => false,
+        };
+        assert!(GAME_STATES.iter().all(listed));
+        assert!(!GAME_STATES.contains(&GameState::LoadingFailed));
+        assert_eq!(GAME_STATES.len(), 5);
+    }
+
+    #[test]
+    fn created_drinks_list_every_drink() {
+        let index = |drink: &CreatedDrink| match drink {
+            CreatedDrink::ZeroPhase => 0,
+            CreatedDrink::CryoDrop => 1,
+            CreatedDrink::StellarLumen => 2,
+            CreatedDrink::Cosmopolitan => 3,
+            CreatedDrink::SynthCascade => 4,
+            CreatedDrink::OldMemory => 5,
+            CreatedDrink::EchoBloom => 6,
+            CreatedDrink::BotanicalSurge => 7,
+            CreatedDrink::BinaryBarrel => 8,
+            CreatedDrink::EventHorizon => 9,
+        };
+        let indices: Vec<usize> = CREATED_DRINKS.iter().map(index).collect();
+        assert_eq!(indices, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn primary_effects_list_every_effect() {
+        let index = |effect: &PrimaryEffect| match effect {
+            PrimaryEffect::Calming => 0,
+            PrimaryEffect::Energizing => 1,
+            PrimaryEffect::MindEnhancing => 2,
+            PrimaryEffect::CourageBoosting => 3,
+            PrimaryEffect::TruthInducing => 4,
+            PrimaryEffect::Healing => 5,
+        };
+        let indices: Vec<usize> = PRIMARY_EFFECTS.iter().map(index).collect();
+        assert_eq!(indices, (0..6).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn dialogue_rejects_scenes_without_a_patron() {
+        assert!(matches!(
+            parse_command("dialogue ZaraEnters"),
+            Ok(ConsoleCommand::Dialogue(DialogueState::ZaraEnters))
+        ));
+        assert!(parse_command("dialogue MysteryEnters").is_err());
+    }
+}
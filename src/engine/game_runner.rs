@@ -3,14 +3,25 @@ use bevy::render::camera::ScalingMode;
 
 use bevy_asset_loader::prelude::*;
 use bevy_seedling::prelude::*;
+use iyes_progress::ProgressPlugin;
 
 use crate::{
+    animation::SpriteAnimationPlugin,
     bar::crafting::CraftingPlugin,
     customers::CustomerPlugin,
     engine::{
         GameState,
-        asset_loader::{AudioAssets, ImageAssets},
+        asset_loader::{
+            AnimationAssets, AudioAssets, ImageAssets, LoadFailures, record_load_failures,
+        },
+        audio_controller::AudioControllerPlugin,
+        camera_transition::CameraTransitionPlugin,
+        launch::{LaunchOptions, LaunchPlugin},
+        layout::{LayoutPlugin, REFERENCE_RESOLUTION},
+        pause::PausePlugin,
+        settings::SettingsPlugin,
     },
+    localization::LocalizationPlugin,
     ui::GameUiPlugin,
 };
 
@@ -18,19 +29,53 @@ pub struct GameRunnerPlugin;
 
 impl Plugin for GameRunnerPlugin {
     fn build(&self, app: &mut App) {
+        let after_loading = app
+            .world()
+            .get_resource::<LaunchOptions>()
+            .map(LaunchOptions::state_after_loading)
+            .unwrap_or(GameState::Dialogues);
         app.add_plugins((
-            SeedlingPlugin::default(),
+            // In the browser `main` only gets here after a click, so the audio
+            // context this opens is allowed to play.
+            SeedlingPlugin {
+                // `AudioControllerPlugin` spawns it behind the SFX bus.
+                spawn_default_pool: false,
+                ..SeedlingPlugin::default()
+            },
+            SpriteAnimationPlugin,
+            LocalizationPlugin,
+            SettingsPlugin,
+            AudioControllerPlugin,
+            PausePlugin,
+            CameraTransitionPlugin,
+            LayoutPlugin,
             GameUiPlugin,
             CustomerPlugin,
             CraftingPlugin,
+            LaunchPlugin,
+            // Moves on to Dialogues, or where the launch options say, once
+            // every tracked collection reports done.
+            ProgressPlugin::<GameState>::new()
+                .with_state_transition(GameState::Loading, after_loading),
         ))
+        .init_resource::<LoadFailures>()
         .add_loading_state(
             LoadingState::new(GameState::Loading)
                 .load_collection::<AudioAssets>()
                 .load_collection::<ImageAssets>()
-                .continue_to_state(GameState::Dialogues),
+                .load_collection::<AnimationAssets>()
+                .on_failure_continue_to_state(GameState::LoadingFailed),
         )
-        .add_systems(Startup, setup_camera);
+        .add_systems(Startup, setup_camera)
+        // Unconditional, so a failure reported in the same frame as the
+        // state change is still recorded.
+        .add_systems(Update, record_load_failures);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Startup, crate::engine::asset_loader::check_asset_manifest);
+
+        #[cfg(feature = "dev")]
+        app.add_plugins(crate::engine::dev_console::DevConsolePlugin);
     }
 }
 
@@ -38,10 +83,13 @@ impl Plugin for GameRunnerPlugin {
 pub struct MainGameCamera;
 
 fn setup_camera(mut commands: Commands) {
-    let main_camera = Camera2d::default();
+    let main_camera = Camera2d;
     let projection = Projection::Orthographic(OrthographicProjection {
-        scaling_mode: ScalingMode::FixedVertical {
-            viewport_height: 1080.0,
+        // The whole reference area stays in view; wider or taller windows see
+        // more around it instead of cropping the scene.
+        scaling_mode: ScalingMode::AutoMin {
+            min_width: REFERENCE_RESOLUTION.x,
+            min_height: REFERENCE_RESOLUTION.y,
         },
         ..OrthographicProjection::default_2d()
     });
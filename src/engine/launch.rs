@@ -0,0 +1,290 @@
+use bevy::prelude::*;
+use bevy_yarnspinner::prelude::*;
+
+use crate::{
+    dialogues::{DialogueState, NightVariables},
+    engine::{GameState, settings::GameSettings},
+};
+
+pub const USAGE: &str = "\
+Usage: cosmos-on-the-rocks [options]
+
+Options skip the main menu and start the night from the given point once assets load.
+  --state <dialogues|crafting|endnight>  state to enter after loading (default dialogues)
+  --patron <bartender|carl|zara|coda>
+  --node <Node>                          Yarn node the first conversation starts at
+  --set <variable>=<value>               Yarn variable, repeatable; the `$` is optional
+  --night <n>                            sets $night for scripts that branch on it
+  --seed <n>                             seed for anything random
+  --windowed <width>x<height>            windowed at this size for the session, without saving it
+  --help";
+
+/// Yarn variable `--night` sets.
+const NIGHT_VARIABLE: &str = "$night";
+
+pub struct LaunchPlugin;
+
+impl Plugin for LaunchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LaunchOptions>()
+            .add_systems(Startup, skip_main_menu);
+    }
+}
+
+/// Where to start, from the command line. Empty unless the game was launched
+/// with options.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct LaunchOptions {
+    /// State loading leads to instead of `Dialogues`, for the whole session.
+    pub state: Option<GameState>,
+    pub patron: Option<DialogueState>,
+    /// Taken by the first dialogue runner, so later visits start normally.
+    pub node: Option<String>,
+    pub variables: Vec<(String, YarnValue)>,
+    pub night: Option<u32>,
+    /// Recorded for reproducing a run; nothing in the night is random yet.
+    pub seed: Option<u64>,
+    pub windowed: Option<UVec2>,
+}
+
+impl LaunchOptions {
+    /// Parses arguments after the program name. `--help` is left to the caller.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+        while let Some(flag) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{flag} needs a value"));
+            match flag.as_str() {
+                "--state" => {
+                    let state = value()?;
+                    options.state = Some(match state.to_lowercase().as_str() {
+                        "dialogues" => GameState::Dialogues,
+                        "crafting" => GameState::Crafting,
+                        "endnight" | "end" => GameState::EndNight,
+                        _ => return Err(format!("Unknown state {state}")),
+                    });
+                }
+                "--patron" => {
+                    let patron = value()?;
+                    let state = match patron.to_lowercase().as_str() {
+                        "bartender" => DialogueState::BartenderMonologue,
+                        "carl" => DialogueState::CarlEnters,
+                        "zara" => DialogueState::ZaraEnters,
+                        "coda" => DialogueState::CodaEnters,
+                        "mystery" => DialogueState::MysteryEnters,
+                        _ => return Err(format!("Unknown patron {patron}")),
+                    };
+                    if !state.is_playable() {
+                        return Err(format!("{patron} cannot be launched into yet"));
+                    }
+                    options.patron = Some(state);
+                }
+                "--node" => options.node = Some(value()?),
+                "--set" => {
+                    let assignment = value()?;
+                    let Some((name, value)) = assignment.split_once('=') else {
+                        return Err(format!("Expected <variable>=<value>, got {assignment}"));
+                    };
+                    options
+                        .variables
+                        .push((variable_name(name.trim()), parse_yarn_value(value.trim())));
+                }
+                "--night" => {
+                    let night = value()?;
+                    options.night = Some(
+                        night
+                            .parse()
+                            .map_err(|_| format!("Invalid night {night}"))?,
+                    );
+                }
+                "--seed" => {
+                    let seed = value()?;
+                    options.seed = Some(seed.parse().map_err(|_| format!("Invalid seed {seed}"))?);
+                }
+                "--windowed" => {
+                    let size = value()?;
+                    let parsed = size.split_once('x').and_then(|(width, height)| {
+                        Some(UVec2::new(width.parse().ok()?, height.parse().ok()?))
+                    });
+                    options.windowed =
+                        Some(parsed.ok_or_else(|| format!("Invalid window size {size}"))?);
+                }
+                other => return Err(format!("Unknown option {other}")),
+            }
+        }
+        Ok(options)
+    }
+
+    /// Whether the options describe a point in the night to jump to.
+    pub fn skips_menu(&self) -> bool {
+        self.state.is_some()
+            || self.patron.is_some()
+            || self.node.is_some()
+            || !self.variables.is_empty()
+            || self.night.is_some()
+    }
+
+    /// The saved settings with the display overrides on top. Only used to set
+    /// up the window, so the overrides never reach `settings.cfg`.
+    pub fn display_settings(&self, saved: &GameSettings) -> GameSettings {
+        let mut settings = saved.clone();
+        if let Some(size) = self.windowed {
+            settings.fullscreen = false;
+            settings.resolution = size;
+        }
+        settings
+    }
+
+    pub fn state_after_loading(&self) -> GameState {
+        self.state.clone().unwrap_or(GameState::Dialogues)
+    }
+}
+
+/// Yarn variables always start with `$`; it may be left off when typing one.
+pub fn variable_name(name: &str) -> String {
+    if name.starts_with('$') {
+        name.to_string()
+    } else {
+        format!("${name}")
+    }
+}
+
+/// Numbers and `true`/`false` become those Yarn types, anything else a string.
+pub fn parse_yarn_value(value: &str) -> YarnValue {
+    if let Ok(number) = value.parse() {
+        YarnValue::Number(number)
+    } else if let Ok(boolean) = value.parse() {
+        YarnValue::Boolean(boolean)
+    } else {
+        YarnValue::String(value.to_string())
+    }
+}
+
+fn skip_main_menu(
+    options: Res<LaunchOptions>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut dialogue_state: ResMut<NextState<DialogueState>>,
+    mut night_variables: ResMut<NightVariables>,
+) {
+    if let Some(seed) = options.seed {
+        info!("Launch seed {}", seed);
+    }
+    if !options.skips_menu() {
+        return;
+    }
+    info!("Launching into {:?}", options);
+    dialogue_state.set(options.patron.clone().unwrap_or_default());
+    night_variables.0.clear();
+    if let Some(night) = options.night {
+        night_variables
+            .0
+            .insert(NIGHT_VARIABLE.to_string(), YarnValue::Number(night as f32));
+    }
+    for (name, value) in options.variables.iter() {
+        night_variables.0.insert(name.clone(), value.clone());
+    }
+    game_state.set(GameState::Loading);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<LaunchOptions, String> {
+        LaunchOptions::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn no_arguments_keep_the_main_menu() {
+        let options = parse(&[]).unwrap();
+        assert!(!options.skips_menu());
+        assert_eq!(options.state_after_loading(), GameState::Dialogues);
+    }
+
+    #[test]
+    fn flags_set_the_starting_point() {
+        let options = parse(&[
+            "--state",
+            "Crafting",
+            "--patron",
+            "zara",
+            "--node",
+            "ZaraTruthful",
+            "--night",
+            "2",
+            "--seed",
+            "42",
+        ])
+        .unwrap();
+        assert_eq!(options.state, Some(GameState::Crafting));
+        assert_eq!(options.patron, Some(DialogueState::ZaraEnters));
+        assert_eq!(options.node.as_deref(), Some("ZaraTruthful"));
+        assert_eq!(options.night, Some(2));
+        assert_eq!(options.seed, Some(42));
+        assert!(options.skips_menu());
+        assert_eq!(
+            parse(&["--state", "end"]).unwrap().state,
+            Some(GameState::EndNight)
+        );
+    }
+
+    #[test]
+    fn set_values_are_typed() {
+        let options = parse(&[
+            "--set",
+            "truth_effect=6",
+            "--set",
+            "$met_zara = true",
+            "--set",
+            "mood=wary but curious",
+        ])
+        .unwrap();
+        assert_eq!(
+            options.variables,
+            [
+                ("$truth_effect".to_string(), YarnValue::Number(6.0)),
+                ("$met_zara".to_string(), YarnValue::Boolean(true)),
+                (
+                    "$mood".to_string(),
+                    YarnValue::String("wary but curious".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn windowed_overrides_the_saved_display() {
+        let options = parse(&["--windowed", "1280x720"]).unwrap();
+        assert_eq!(options.windowed, Some(UVec2::new(1280, 720)));
+        assert!(!options.skips_menu());
+
+        let saved = GameSettings {
+            fullscreen: true,
+            ..GameSettings::default()
+        };
+        let display = options.display_settings(&saved);
+        assert!(!display.fullscreen);
+        assert_eq!(display.resolution, UVec2::new(1280, 720));
+        // What the settings menu saves is left as it was read.
+        assert!(saved.fullscreen);
+        assert_eq!(saved.resolution, GameSettings::default().resolution);
+    }
+
+    #[test]
+    fn bad_arguments_are_errors() {
+        for args in [
+            &["--state", "sleeping"][..],
+            &["--patron", "nobody"],
+            &["--patron", "mystery"],
+            &["--set", "no_equals_sign"],
+            &["--night", "two"],
+            &["--seed", "-1"],
+            &["--windowed", "1280"],
+            &["--windowed", "widex720"],
+            &["--node"],
+            &["--fullscreen"],
+        ] {
+            assert!(parse(args).is_err(), "{args:?} should not parse");
+        }
+    }
+}
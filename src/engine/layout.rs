@@ -0,0 +1,150 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::engine::{game_runner::MainGameCamera, settings::GameSettings};
+
+/// Area the scene art is drawn for. The camera always shows at least this much
+/// of the world, and the UI is laid out for a window of this size.
+pub const REFERENCE_RESOLUTION: Vec2 = Vec2::new(1920.0, 1080.0);
+
+/// Transform for a point on the reference area, given as fractions of its size
+/// from the centre. The camera always shows the whole reference area, so the
+/// point stays on screen at any aspect ratio.
+pub fn on_reference_area(point: Vec2, z: f32) -> Transform {
+    Transform::from_translation((point * REFERENCE_RESOLUTION).extend(z))
+}
+
+pub struct LayoutPlugin;
+
+impl Plugin for LayoutPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (place_on_anchors, cover_viewport, scale_ui_to_window),
+        );
+    }
+}
+
+/// Scene art other sprites are placed on.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Surface {
+    Shelf,
+    Counter,
+}
+
+/// Named points on a surface's art, as fractions of its size from the centre.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Anchors(pub &'static [(&'static str, Vec2)]);
+
+impl Anchors {
+    pub fn get(&self, name: &str) -> Option<Vec2> {
+        self.0
+            .iter()
+            .find(|(anchor, _)| *anchor == name)
+            .map(|(_, point)| *point)
+    }
+}
+
+/// Keeps the entity on a named anchor of a surface. Only x and y are set; the
+/// entity's own z decides what it is drawn over.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Transform)]
+pub struct AnchoredTo {
+    pub surface: Surface,
+    pub anchor: &'static str,
+}
+
+impl AnchoredTo {
+    pub fn new(surface: Surface, anchor: &'static str) -> Self {
+        Self { surface, anchor }
+    }
+}
+
+/// Sprite scaled up to fill whatever the camera shows, for backdrops on windows
+/// wider or taller than the reference resolution.
+#[derive(Component)]
+pub struct CoverViewport;
+
+fn place_on_anchors(
+    surface_query: Query<(&Surface, &Anchors, &Sprite, Ref<Transform>), Without<AnchoredTo>>,
+    mut anchored_query: Query<(Entity, Ref<AnchoredTo>, &mut Transform)>,
+    images: Res<Assets<Image>>,
+) {
+    let surface_moved = surface_query
+        .iter()
+        .any(|(_, _, _, transform)| transform.is_changed());
+    for (entity, anchored, mut transform) in anchored_query.iter_mut() {
+        if !surface_moved && !anchored.is_added() {
+            continue;
+        }
+        let Some((_, anchors, sprite, surface_transform)) = surface_query
+            .iter()
+            .find(|(surface, ..)| **surface == anchored.surface)
+        else {
+            continue;
+        };
+        let Some(point) = anchors.get(anchored.anchor) else {
+            warn!(
+                "{entity} is anchored to missing point {:?}",
+                anchored.anchor
+            );
+            continue;
+        };
+        let Some(art_size) = sprite
+            .custom_size
+            .or_else(|| images.get(&sprite.image).map(Image::size_f32))
+        else {
+            warn!("{:?} has no size to place {entity} on", anchored.surface);
+            continue;
+        };
+        let size = art_size * surface_transform.scale.truncate();
+        let position = surface_transform.translation.truncate() + point * size;
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+fn cover_viewport(
+    camera_query: Query<&Projection, (With<MainGameCamera>, Changed<Projection>)>,
+    mut cover_query: Query<(&Sprite, &mut Transform), With<CoverViewport>>,
+    mut visible: Local<Vec2>,
+) {
+    if let Some(Projection::Orthographic(orthographic)) = camera_query.iter().next() {
+        // Zoom transitions change the scale; backdrops fit the view at rest.
+        *visible = orthographic.area.size() / orthographic.scale;
+    }
+    if *visible == Vec2::ZERO {
+        return;
+    }
+    for (sprite, mut transform) in cover_query.iter_mut() {
+        let Some(size) = sprite.custom_size else {
+            continue;
+        };
+        let scale = (*visible / size).max_element().max(1.0);
+        if transform.scale.x != scale {
+            transform.scale = Vec3::new(scale, scale, 1.0);
+        }
+    }
+}
+
+/// The player's UI scale, multiplied by how far the window is from the
+/// reference size on its tighter axis so panels never run off screen.
+fn scale_ui_to_window(
+    settings: Res<GameSettings>,
+    window_query: Query<Ref<Window>, With<PrimaryWindow>>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    if !window.is_changed() && !settings.is_changed() {
+        return;
+    }
+    let fit = (window.size() / REFERENCE_RESOLUTION).min_element();
+    if fit <= 0.0 {
+        return;
+    }
+    let scale = settings.ui_scale * fit;
+    if ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+}
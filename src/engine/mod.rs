@@ -1,14 +1,27 @@
 use bevy::prelude::*;
 
 pub mod asset_loader;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod asset_manifest;
 pub mod audio_controller;
+pub mod camera_transition;
+#[cfg(feature = "dev")]
+pub mod dev_console;
 pub mod game_runner;
+pub mod launch;
+pub mod layout;
+pub mod pause;
+pub mod save_game;
+pub mod settings;
+pub mod storage;
 
 #[derive(States, Clone, Eq, PartialEq, Debug, Hash, Default)]
 pub enum GameState {
     #[default]
     MainMenu,
     Loading,
+    /// An asset from a collection could not be loaded; see `LoadFailures`.
+    LoadingFailed,
     Dialogues,
     Crafting,
     EndNight,
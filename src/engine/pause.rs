@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use crate::engine::GameState;
+
+/// Only exists while a night is being played, so pausing never re-runs the
+/// `OnEnter` spawners of the scene underneath.
+#[derive(SubStates, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[source(GameState = GameState::Dialogues | GameState::Crafting)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+}
+
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_sub_state::<PauseState>()
+            .add_systems(OnEnter(PauseState::Paused), freeze_time)
+            .add_systems(OnExit(PauseState::Paused), unfreeze_time);
+    }
+}
+
+/// Stops every `Time`-driven timer: sprite animations, typewriter text and toasts.
+fn freeze_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+fn unfreeze_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_yarnspinner::prelude::*;
+
+use crate::{
+    dialogues::{DialogueState, NightVariables},
+    engine::storage,
+};
+
+pub const SAVE_PATH: &str = "savegame.cfg";
+
+/// Which patron the night is on plus every Yarn variable set so far.
+#[derive(Clone, Debug, Default)]
+pub struct SaveGame {
+    pub dialogue_state: DialogueState,
+    pub variables: HashMap<String, YarnValue>,
+}
+
+impl SaveGame {
+    pub fn exists() -> bool {
+        storage::exists(SAVE_PATH)
+    }
+
+    pub fn capture(
+        dialogue_state: &DialogueState,
+        runner: Option<&DialogueRunner>,
+        night_variables: &NightVariables,
+    ) -> Self {
+        Self {
+            dialogue_state: dialogue_state.clone(),
+            variables: runner
+                .map(|runner| runner.variable_storage().variables())
+                .unwrap_or_else(|| night_variables.0.clone()),
+        }
+    }
+
+    pub fn write(&self) {
+        let mut contents = format!("dialogue_state = {}\n", self.dialogue_state.node_name());
+        let mut names: Vec<&String> = self.variables.keys().collect();
+        names.sort();
+        for name in names {
+            let value = match &self.variables[name] {
+                YarnValue::Number(number) => format!("number:{}", number),
+                YarnValue::Boolean(boolean) => format!("bool:{}", boolean),
+                YarnValue::String(string) => format!("string:{}", string),
+            };
+            contents.push_str(&format!("var {} = {}\n", name, value));
+        }
+        match storage::write(SAVE_PATH, &contents) {
+            Ok(()) => info!("Game saved to {}", SAVE_PATH),
+            Err(err) => error!("Failed to save game to {}: {}", SAVE_PATH, err),
+        }
+    }
+
+    pub fn read() -> Option<Self> {
+        let source = storage::read(SAVE_PATH)?;
+        let mut save = SaveGame::default();
+        for line in source.lines() {
+            let Some((key, value)) = line.split_once(" = ") else {
+                continue;
+            };
+            if key == "dialogue_state" {
+                save.dialogue_state = DialogueState::from_node_name(value)?;
+            } else if let Some(name) = key.strip_prefix("var ") {
+                let value = match value.split_once(':') {
+                    Some(("number", number)) => YarnValue::Number(number.parse().ok()?),
+                    Some(("bool", boolean)) => YarnValue::Boolean(boolean.parse().ok()?),
+                    Some(("string", string)) => YarnValue::String(string.to_string()),
+                    _ => {
+                        warn!("Skipping malformed save variable {}", name);
+                        continue;
+                    }
+                };
+                save.variables.insert(name.to_string(), value);
+            }
+        }
+        Some(save)
+    }
+}
@@ -0,0 +1,254 @@
+use std::ops::RangeInclusive;
+
+use bevy::{
+    prelude::*,
+    window::{MonitorSelection, PresentMode, PrimaryWindow, WindowMode, WindowResolution},
+};
+
+use crate::{
+    dialogues::dialogue_view::LinePresentation,
+    engine::{launch::LaunchOptions, storage},
+    localization::{Locale, Localization},
+};
+
+pub const SETTINGS_PATH: &str = "settings.cfg";
+
+pub const RESOLUTIONS: [UVec2; 4] = [
+    UVec2::new(1280, 720),
+    UVec2::new(1600, 900),
+    UVec2::new(1920, 1080),
+    UVec2::new(2560, 1440),
+];
+
+/// Ranges the settings menu steps within; values read from the file are
+/// clamped to them as well.
+pub const VOLUME_RANGE: RangeInclusive<f32> = 0.0..=1.0;
+pub const TEXT_SPEED_RANGE: RangeInclusive<f32> = 10.0..=120.0;
+pub const UI_SCALE_RANGE: RangeInclusive<f32> = 0.5..=2.0;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (apply_display_settings, apply_gameplay_settings)
+                .run_if(resource_changed::<GameSettings>),
+        );
+    }
+}
+
+/// Player options, saved to [`SETTINGS_PATH`] as `key = value` lines.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct GameSettings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub fullscreen: bool,
+    pub resolution: UVec2,
+    pub vsync: bool,
+    /// Characters per second for dialogue lines.
+    pub text_speed: f32,
+    pub ui_scale: f32,
+    pub locale: Locale,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 0.8,
+            sfx_volume: 1.0,
+            fullscreen: false,
+            resolution: RESOLUTIONS[2],
+            vsync: true,
+            text_speed: 40.0,
+            ui_scale: 1.0,
+            locale: Locale::default(),
+        }
+    }
+}
+
+impl GameSettings {
+    /// Reads the config file, keeping defaults for anything missing or malformed.
+    pub fn load() -> Self {
+        match storage::read(SETTINGS_PATH) {
+            Some(source) => Self::parse(&source),
+            None => Self::default(),
+        }
+    }
+
+    pub fn parse(source: &str) -> Self {
+        let mut settings = Self::default();
+        for line in source.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "master_volume" => parse_in_range(value, &mut settings.master_volume, VOLUME_RANGE),
+                "music_volume" => parse_in_range(value, &mut settings.music_volume, VOLUME_RANGE),
+                "sfx_volume" => parse_in_range(value, &mut settings.sfx_volume, VOLUME_RANGE),
+                "fullscreen" => parse_into(value, &mut settings.fullscreen),
+                "vsync" => parse_into(value, &mut settings.vsync),
+                "text_speed" => parse_in_range(value, &mut settings.text_speed, TEXT_SPEED_RANGE),
+                "ui_scale" => parse_in_range(value, &mut settings.ui_scale, UI_SCALE_RANGE),
+                "resolution" => {
+                    if let Some((width, height)) = value.split_once('x')
+                        && let (Ok(width), Ok(height)) = (width.parse(), height.parse())
+                    {
+                        settings.resolution = UVec2::new(width, height);
+                    }
+                }
+                "language" => {
+                    if let Some(locale) = Locale::from_code(value) {
+                        settings.locale = locale;
+                    }
+                }
+                other => warn!("Unknown setting {} in {}", other, SETTINGS_PATH),
+            }
+        }
+        settings
+    }
+
+    pub fn save(&self) {
+        if let Err(err) = storage::write(SETTINGS_PATH, &self.to_config()) {
+            error!("Failed to save settings to {}: {}", SETTINGS_PATH, err);
+        }
+    }
+
+    /// The file [`GameSettings::parse`] reads back.
+    pub fn to_config(&self) -> String {
+        format!(
+            "master_volume = {}\nmusic_volume = {}\nsfx_volume = {}\nfullscreen = {}\nresolution = {}x{}\nvsync = {}\ntext_speed = {}\nui_scale = {}\nlanguage = {}\n",
+            self.master_volume,
+            self.music_volume,
+            self.sfx_volume,
+            self.fullscreen,
+            self.resolution.x,
+            self.resolution.y,
+            self.vsync,
+            self.text_speed,
+            self.ui_scale,
+            self.locale.code(),
+        )
+    }
+
+    pub fn window_mode(&self) -> WindowMode {
+        if self.fullscreen {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        } else {
+            WindowMode::Windowed
+        }
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        if self.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        }
+    }
+
+    pub fn window_resolution(&self) -> WindowResolution {
+        WindowResolution::new(self.resolution.x as f32, self.resolution.y as f32)
+    }
+}
+
+fn parse_into<T: std::str::FromStr>(value: &str, target: &mut T) {
+    if let Ok(parsed) = value.parse() {
+        *target = parsed;
+    }
+}
+
+/// Skips values that are not finite numbers and clamps the rest into `range`.
+fn parse_in_range(value: &str, target: &mut f32, range: RangeInclusive<f32>) {
+    if let Ok(parsed) = value.parse::<f32>()
+        && parsed.is_finite()
+    {
+        *target = parsed.clamp(*range.start(), *range.end());
+    }
+}
+
+fn apply_display_settings(
+    settings: Res<GameSettings>,
+    launch_options: Res<LaunchOptions>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    // Launch overrides hold for the whole session but stay out of the saved settings.
+    let settings = launch_options.display_settings(&settings);
+    for mut window in window_query.iter_mut() {
+        window.mode = settings.window_mode();
+        window.present_mode = settings.present_mode();
+        // In the browser the canvas follows the page instead.
+        #[cfg(not(target_arch = "wasm32"))]
+        window
+            .resolution
+            .set(settings.resolution.x as f32, settings.resolution.y as f32);
+    }
+}
+
+fn apply_gameplay_settings(
+    settings: Res<GameSettings>,
+    mut presentation: ResMut<LinePresentation>,
+    mut localization: ResMut<Localization>,
+) {
+    presentation.chars_per_second = settings.text_speed;
+    if localization.locale != settings.locale {
+        localization.locale = settings.locale;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_the_config_file() {
+        let settings = GameSettings {
+            master_volume: 0.5,
+            music_volume: 0.25,
+            sfx_volume: 0.0,
+            fullscreen: true,
+            resolution: UVec2::new(1600, 900),
+            vsync: false,
+            text_speed: 80.0,
+            ui_scale: 1.25,
+            locale: Locale::German,
+        };
+        assert_eq!(GameSettings::parse(&settings.to_config()), settings);
+    }
+
+    #[test]
+    fn keeps_defaults_for_missing_and_malformed_values() {
+        let settings = GameSettings::parse(
+            "music_volume = 0.3\nsfx_volume = loud\nresolution = 1024\nlanguage = fr-FR\nno equals sign\ncolour = blue\n",
+        );
+        assert_eq!(
+            settings,
+            GameSettings {
+                music_volume: 0.3,
+                ..GameSettings::default()
+            }
+        );
+    }
+
+    #[test]
+    fn clamps_out_of_range_numbers_and_skips_non_finite_ones() {
+        let settings = GameSettings::parse(
+            "master_volume = -0.5\nmusic_volume = 3\nsfx_volume = NaN\ntext_speed = inf\nui_scale = 40\n",
+        );
+        assert_eq!(settings.master_volume, 0.0);
+        assert_eq!(settings.music_volume, 1.0);
+        assert_eq!(settings.sfx_volume, GameSettings::default().sfx_volume);
+        assert_eq!(settings.text_speed, GameSettings::default().text_speed);
+        assert_eq!(settings.ui_scale, 2.0);
+    }
+
+    #[test]
+    fn parses_around_whitespace() {
+        let settings = GameSettings::parse("  fullscreen=true  \nresolution =2560x1440\n");
+        assert!(settings.fullscreen);
+        assert_eq!(settings.resolution, UVec2::new(2560, 1440));
+    }
+}
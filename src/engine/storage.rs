@@ -0,0 +1,49 @@
+//! Small text files for saves and settings: files next to the game natively,
+//! `localStorage` entries in the browser.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod platform {
+    use std::fs;
+
+    pub fn read(name: &str) -> Option<String> {
+        fs::read_to_string(name).ok()
+    }
+
+    pub fn write(name: &str, contents: &str) -> Result<(), String> {
+        fs::write(name, contents).map_err(|err| err.to_string())
+    }
+
+    pub fn exists(name: &str) -> bool {
+        fs::metadata(name).is_ok()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    /// Pages on a shared host (itch.io serves every game from one origin) see
+    /// the same storage, so keys are namespaced by the game.
+    const KEY_PREFIX: &str = "cosmos-on-the-rocks/";
+
+    fn local_storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+
+    pub fn read(name: &str) -> Option<String> {
+        local_storage()?
+            .get_item(&format!("{KEY_PREFIX}{name}"))
+            .ok()?
+    }
+
+    pub fn write(name: &str, contents: &str) -> Result<(), String> {
+        local_storage()
+            .ok_or_else(|| "localStorage is unavailable".to_string())?
+            .set_item(&format!("{KEY_PREFIX}{name}"), contents)
+            .map_err(|err| format!("{err:?}"))
+    }
+
+    pub fn exists(name: &str) -> bool {
+        read(name).is_some()
+    }
+}
+
+pub use platform::{exists, read, write};
@@ -1,25 +1,25 @@
 use crate::{
-    animation::sprite_animation::SpriteAnimState,
-    engine::asset_loader::ImageAssets,
+    animation::sprite_animation::AnimationPlayer2d,
+    bar::temperature::ICEGEL_TEMPERATURE,
+    engine::asset_loader::{AnimationAssets, ImageAssets},
     ingredients::{
-        EffectCondition, Ingredient, IngredientProfile, IngredientTaste, PrimaryEffect,
-        SecondaryEffect,
+        EffectCondition, Ingredient, IngredientId, IngredientProfile, IngredientTaste,
+        PrimaryEffect, SecondaryEffect,
     },
+    localization::Localization,
 };
 use bevy::prelude::*;
 
 pub fn get_ice_gels(
     image_assets: &Res<ImageAssets>,
+    animation_assets: &AnimationAssets,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
-) -> Vec<(Ingredient, Sprite, Transform, SpriteAnimState)> {
+    localization: &Localization,
+) -> Vec<(Ingredient, Sprite, AnimationPlayer2d)> {
     let frame_size = UVec2::new(128, 128);
     let icegel_layout_handle =
         texture_atlases.add(TextureAtlasLayout::from_grid(frame_size, 8, 1, None, None));
-    let icegel_anim_state = SpriteAnimState {
-        start_index: 0,
-        end_index: 7,
-        timer: Timer::from_seconds(1.0 / 12.0, TimerMode::Repeating),
-    };
+    let icegel_player = AnimationPlayer2d::new(animation_assets.icegel.clone(), "idle");
     let blue_icegel_sprite = Sprite {
         image: image_assets.blue_icegel.clone(),
         texture_atlas: Some(TextureAtlas {
@@ -57,6 +57,7 @@ pub fn get_ice_gels(
             catalyst: None,
         }),
         hazard: None,
+        temperature: ICEGEL_TEMPERATURE,
     };
     let red_icegel_profile = IngredientProfile {
         size: 10.0,
@@ -67,6 +68,7 @@ pub fn get_ice_gels(
             catalyst: None,
         }),
         hazard: None,
+        temperature: ICEGEL_TEMPERATURE,
     };
     let green_icegel_profile = IngredientProfile {
         size: 10.0,
@@ -77,42 +79,39 @@ pub fn get_ice_gels(
             catalyst: None,
         }),
         hazard: None,
+        temperature: ICEGEL_TEMPERATURE,
     };
 
     let blue_icegel = Ingredient {
-        name: "Blue Icegel".to_string(),
-        description: "Cools down drinks".to_string(),
+        id: IngredientId::BlueIcegel,
+        name: localization.get("ingredient.blue_icegel.name"),
+        description: localization.get("ingredient.blue_icegel.description"),
         ingredient_profile: blue_icegel_profile,
     };
     let red_icegel_ingredient = Ingredient {
-        name: "Red Icegel".to_string(),
-        description: "Cools down drinks".to_string(),
+        id: IngredientId::RedIcegel,
+        name: localization.get("ingredient.red_icegel.name"),
+        description: localization.get("ingredient.red_icegel.description"),
         ingredient_profile: red_icegel_profile,
     };
     let green_icegel_ingredient = Ingredient {
-        name: "Green Icegel".to_string(),
-        description: "Cools down drinks".to_string(),
+        id: IngredientId::GreenIcegel,
+        name: localization.get("ingredient.green_icegel.name"),
+        description: localization.get("ingredient.green_icegel.description"),
         ingredient_profile: green_icegel_profile,
     };
 
     vec![
-        (
-            blue_icegel,
-            blue_icegel_sprite,
-            Transform::from_xyz(-450.0, -200.0, 1.1),
-            icegel_anim_state.clone(),
-        ),
+        (blue_icegel, blue_icegel_sprite, icegel_player.clone()),
         (
             red_icegel_ingredient,
             red_icegel_sprite,
-            Transform::from_xyz(-400.0, -200.0, 1.0),
-            icegel_anim_state.clone(),
+            icegel_player.clone(),
         ),
         (
             green_icegel_ingredient,
             green_icegel_sprite,
-            Transform::from_xyz(-350.0, -200.0, 1.1),
-            icegel_anim_state.clone(),
+            icegel_player.clone(),
         ),
     ]
 }
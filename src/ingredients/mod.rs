@@ -1,24 +1,91 @@
 use bevy::{picking::prelude::Pickable, prelude::*};
 
 use crate::{
-    animation::sprite_animation::SpriteAnimState,
-    bar::crafting::OnCraftingScreen,
-    engine::asset_loader::ImageAssets,
+    bar::{
+        bar_counter::{ICEGEL_SLOTS, SHELF_SLOTS},
+        crafting::OnCraftingScreen,
+    },
+    engine::{
+        asset_loader::{AnimationAssets, ImageAssets},
+        layout::{AnchoredTo, Surface},
+    },
     ingredients::{animated_ingredients::get_ice_gels, static_ingredients::get_static_ingredients},
-    ui::ingredient_tooltip::{ingredient_hover, ingredient_hover_out, ingredient_pressed},
+    localization::Localization,
+    ui::{
+        ingredient_tooltip::{ingredient_hover, ingredient_hover_out, ingredient_pressed},
+        navigation::{Focusable, NavActivate},
+    },
 };
 
 pub mod animated_ingredients;
 pub mod static_ingredients;
 
 #[derive(Component, Clone, Debug)]
-#[require(Sprite, Transform, SpriteAnimState)]
+#[require(Sprite, Transform)]
 pub struct Ingredient {
+    pub id: IngredientId,
     pub name: String,
     pub description: String,
     pub ingredient_profile: IngredientProfile,
 }
 
+/// Which bottle an ingredient is, independent of its localized name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IngredientId {
+    FizzionMist,
+    Sweetflux,
+    Citraplasm,
+    SynthVapor,
+    CircuitJuice,
+    VoidReserve,
+    BlueIcegel,
+    RedIcegel,
+    GreenIcegel,
+}
+
+impl IngredientId {
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            IngredientId::FizzionMist => "ingredient.fizzion_mist.name",
+            IngredientId::Sweetflux => "ingredient.sweetflux.name",
+            IngredientId::Citraplasm => "ingredient.citraplasm.name",
+            IngredientId::SynthVapor => "ingredient.synth_vapor.name",
+            IngredientId::CircuitJuice => "ingredient.circuit_juice.name",
+            IngredientId::VoidReserve => "ingredient.void_reserve.name",
+            IngredientId::BlueIcegel => "ingredient.blue_icegel.name",
+            IngredientId::RedIcegel => "ingredient.red_icegel.name",
+            IngredientId::GreenIcegel => "ingredient.green_icegel.name",
+        }
+    }
+
+    pub fn is_icegel(&self) -> bool {
+        matches!(
+            self,
+            IngredientId::BlueIcegel | IngredientId::RedIcegel | IngredientId::GreenIcegel
+        )
+    }
+}
+
+/// Scale a bottle springs back to after being tipped.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct RestScale(pub Vec3);
+
+/// Position on the shelf, mapped to the number keys. Bottles fill the shelf
+/// slots first; the rest go on the counter's icegel tray.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ShelfSlot(pub usize);
+
+impl ShelfSlot {
+    pub fn anchor(&self) -> Option<AnchoredTo> {
+        match SHELF_SLOTS.get(self.0) {
+            Some(anchor) => Some(AnchoredTo::new(Surface::Shelf, anchor)),
+            None => ICEGEL_SLOTS
+                .get(self.0 - SHELF_SLOTS.len())
+                .map(|anchor| AnchoredTo::new(Surface::Counter, anchor)),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct IngredientProfile {
     pub size: f32,
@@ -26,6 +93,8 @@ pub struct IngredientProfile {
     pub primary_effect: PrimaryEffect,
     pub secondary_effect: SecondaryEffect,
     pub hazard: Option<String>,
+    /// Degrees Celsius the ingredient pours at.
+    pub temperature: f32,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Copy)]
@@ -39,6 +108,20 @@ pub enum IngredientTaste {
     Spicy,
 }
 
+impl IngredientTaste {
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            IngredientTaste::None => "taste.none",
+            IngredientTaste::Sweet => "taste.sweet",
+            IngredientTaste::Sour => "taste.sour",
+            IngredientTaste::Bitter => "taste.bitter",
+            IngredientTaste::Citrus => "taste.citrus",
+            IngredientTaste::Umami => "taste.umami",
+            IngredientTaste::Spicy => "taste.spicy",
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PrimaryEffect {
     Calming,
@@ -49,6 +132,31 @@ pub enum PrimaryEffect {
     Healing,
 }
 
+impl PrimaryEffect {
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            PrimaryEffect::Calming => "effect.calming",
+            PrimaryEffect::Energizing => "effect.energizing",
+            PrimaryEffect::MindEnhancing => "effect.mind_enhancing",
+            PrimaryEffect::CourageBoosting => "effect.courage_boosting",
+            PrimaryEffect::TruthInducing => "effect.truth_inducing",
+            PrimaryEffect::Healing => "effect.healing",
+        }
+    }
+
+    /// Yarn variable the dialogue checks after the drink is consumed.
+    pub fn yarn_variable(&self) -> &'static str {
+        match self {
+            PrimaryEffect::Calming => "$calming_effect",
+            PrimaryEffect::Energizing => "$energizing_effect",
+            PrimaryEffect::MindEnhancing => "$mind_enhancing_effect",
+            PrimaryEffect::CourageBoosting => "$courage_effect",
+            PrimaryEffect::TruthInducing => "$truth_effect",
+            PrimaryEffect::Healing => "$healing_effect",
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum SecondaryEffect {
     Euphoric(EffectCondition),
@@ -59,44 +167,101 @@ pub enum SecondaryEffect {
     Sedated(EffectCondition),
 }
 
+impl SecondaryEffect {
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            SecondaryEffect::Euphoric(_) => "secondary.euphoric",
+            SecondaryEffect::Agitated(_) => "secondary.agitated",
+            SecondaryEffect::Hallucinogenic(_) => "secondary.hallucinogenic",
+            SecondaryEffect::Paranoia(_) => "secondary.paranoia",
+            SecondaryEffect::Aggresive(_) => "secondary.aggressive",
+            SecondaryEffect::Sedated(_) => "secondary.sedated",
+        }
+    }
+
+    pub fn condition(&self) -> &EffectCondition {
+        match self {
+            SecondaryEffect::Euphoric(condition)
+            | SecondaryEffect::Agitated(condition)
+            | SecondaryEffect::Hallucinogenic(condition)
+            | SecondaryEffect::Paranoia(condition)
+            | SecondaryEffect::Aggresive(condition)
+            | SecondaryEffect::Sedated(condition) => condition,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct EffectCondition {
     pub volume_needed: f32,
-    pub catalyst: Option<Entity>,
+    /// Ingredient that must also be in the glass for the effect to kick in.
+    pub catalyst: Option<IngredientId>,
+}
+
+impl EffectCondition {
+    pub fn is_met(&self, amount: f32, has_catalyst: impl Fn(IngredientId) -> bool) -> bool {
+        amount >= self.volume_needed && self.catalyst.is_none_or(has_catalyst)
+    }
 }
 
 pub fn spawn_ingredients(
     mut commands: Commands,
     image_assets: Res<ImageAssets>,
+    animation_assets: Res<AnimationAssets>,
     texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+    localization: Res<Localization>,
 ) {
-    let animated_ingredients = get_ice_gels(&image_assets, texture_atlases);
-    let static_ingredients = get_static_ingredients(&image_assets);
-    for (ingredient, sprite, transform, anim_state) in animated_ingredients {
-        commands
-            .spawn((
-                ingredient,
-                sprite,
-                transform,
-                anim_state,
-                Pickable::default(),
-                OnCraftingScreen,
-            ))
-            .observe(ingredient_pressed)
-            .observe(ingredient_hover)
-            .observe(ingredient_hover_out);
+    let animated_ingredients = get_ice_gels(
+        &image_assets,
+        &animation_assets,
+        texture_atlases,
+        &localization,
+    );
+    let static_ingredients = get_static_ingredients(&image_assets, &localization);
+    let static_count = static_ingredients.len();
+    for (slot, (ingredient, sprite)) in static_ingredients.into_iter().enumerate() {
+        spawn_ingredient(
+            &mut commands,
+            (ingredient, sprite),
+            Transform::from_xyz(0.0, 0.0, 1.0),
+            ShelfSlot(slot),
+        );
     }
-    for (ingredient, sprite, transform) in static_ingredients {
-        commands
-            .spawn((
-                ingredient,
-                sprite,
-                transform,
-                Pickable::default(),
-                OnCraftingScreen,
-            ))
-            .observe(ingredient_pressed)
-            .observe(ingredient_hover)
-            .observe(ingredient_hover_out);
+    for (slot, (ingredient, sprite, animation_player)) in
+        animated_ingredients.into_iter().enumerate()
+    {
+        // Neighbouring icegels overlap, so alternate which one is drawn in front.
+        let z = if slot % 2 == 0 { 1.1 } else { 1.0 };
+        spawn_ingredient(
+            &mut commands,
+            (ingredient, sprite, animation_player),
+            Transform::from_xyz(0.0, 0.0, z),
+            ShelfSlot(static_count + slot),
+        );
+    }
+}
+
+fn spawn_ingredient(
+    commands: &mut Commands,
+    bundle: impl Bundle,
+    transform: Transform,
+    slot: ShelfSlot,
+) {
+    let mut entity = commands.spawn((
+        bundle,
+        transform,
+        RestScale(transform.scale),
+        slot,
+        Focusable,
+        Pickable::default(),
+        OnCraftingScreen,
+    ));
+    if let Some(anchor) = slot.anchor() {
+        entity.insert(anchor);
     }
+    entity
+        .observe(ingredient_pressed::<Pointer<Pressed>>)
+        .observe(ingredient_pressed::<NavActivate>)
+        .observe(ingredient_hover)
+        .observe(ingredient_hover_out);
 }
@@ -1,16 +1,19 @@
 use bevy::prelude::*;
 
 use crate::{
+    bar::temperature::ROOM_TEMPERATURE,
     engine::asset_loader::ImageAssets,
     ingredients::{
-        EffectCondition, Ingredient, IngredientProfile, IngredientTaste, PrimaryEffect,
-        SecondaryEffect,
+        EffectCondition, Ingredient, IngredientId, IngredientProfile, IngredientTaste,
+        PrimaryEffect, SecondaryEffect,
     },
+    localization::Localization,
 };
 
 pub fn get_static_ingredients(
     image_assets: &Res<ImageAssets>,
-) -> Vec<(Ingredient, Sprite, Transform)> {
+    localization: &Localization,
+) -> Vec<(Ingredient, Sprite)> {
     let fizzion_mist_sprite = Sprite {
         image: image_assets.fizzion_mist.clone(),
         custom_size: Some(Vec2::new(128., 128.)),
@@ -51,6 +54,7 @@ pub fn get_static_ingredients(
             catalyst: None,
         }),
         hazard: None,
+        temperature: ROOM_TEMPERATURE,
     };
 
     let sweetflux_profile = IngredientProfile {
@@ -62,6 +66,7 @@ pub fn get_static_ingredients(
             catalyst: None,
         }),
         hazard: None,
+        temperature: ROOM_TEMPERATURE,
     };
 
     let citraplasm_profile = IngredientProfile {
@@ -73,6 +78,7 @@ pub fn get_static_ingredients(
             catalyst: None,
         }),
         hazard: None,
+        temperature: ROOM_TEMPERATURE,
     };
 
     let synth_vapor_profile = IngredientProfile {
@@ -84,6 +90,7 @@ pub fn get_static_ingredients(
             catalyst: None,
         }),
         hazard: None,
+        temperature: ROOM_TEMPERATURE,
     };
 
     let circuit_juice_profile = IngredientProfile {
@@ -95,6 +102,7 @@ pub fn get_static_ingredients(
             catalyst: None,
         }),
         hazard: None,
+        temperature: ROOM_TEMPERATURE,
     };
 
     let void_reserve_profile = IngredientProfile {
@@ -106,78 +114,57 @@ pub fn get_static_ingredients(
             catalyst: None,
         }),
         hazard: None,
+        temperature: ROOM_TEMPERATURE,
     };
 
     let fizzion_mist = Ingredient {
-        name: "Fizzion Mist".to_string(),
-        description:
-            "A bubbly, sour liquid that crackles with energy, leaving a tingling sensation."
-                .to_string(),
+        id: IngredientId::FizzionMist,
+        name: localization.get("ingredient.fizzion_mist.name"),
+        description: localization.get("ingredient.fizzion_mist.description"),
         ingredient_profile: fizzion_mist_profile,
     };
 
     let sweetflux = Ingredient {
-        name: "Sweetflux".to_string(),
-        description: "A luminous, sugary syrup that flows like liquid light, known for its calming properties.".to_string(),
+        id: IngredientId::Sweetflux,
+        name: localization.get("ingredient.sweetflux.name"),
+        description: localization.get("ingredient.sweetflux.description"),
         ingredient_profile: sweetflux_profile,
     };
 
     let citraplasm = Ingredient {
-        name: "Citraplasm".to_string(),
-        description:
-            "A vibrant, zesty plasma with a sharp citrus bite, perfect for stimulating the mind."
-                .to_string(),
+        id: IngredientId::Citraplasm,
+        name: localization.get("ingredient.citraplasm.name"),
+        description: localization.get("ingredient.citraplasm.description"),
         ingredient_profile: citraplasm_profile,
     };
 
     let synth_vapor = Ingredient {
-        name: "Synth Vapor".to_string(),
-        description: "A potent synthetic spirit, clear and almost tasteless, yet it carries a powerful, energizing kick.".to_string(),
+        id: IngredientId::SynthVapor,
+        name: localization.get("ingredient.synth_vapor.name"),
+        description: localization.get("ingredient.synth_vapor.description"),
         ingredient_profile: synth_vapor_profile,
     };
 
     let circuit_juice = Ingredient {
-        name: "Circuit Juice".to_string(),
-        description: "A sharp, botanical spirit with a metallic tang, designed to awaken the senses and enhance focus.".to_string(),
+        id: IngredientId::CircuitJuice,
+        name: localization.get("ingredient.circuit_juice.name"),
+        description: localization.get("ingredient.circuit_juice.description"),
         ingredient_profile: circuit_juice_profile,
     };
 
     let void_reserve = Ingredient {
-        name: "Void Reserve".to_string(),
-        description: "A dense, inky fluid that absorbs light, offering a deep, earthy taste and a profound sense of calm.".to_string(),
+        id: IngredientId::VoidReserve,
+        name: localization.get("ingredient.void_reserve.name"),
+        description: localization.get("ingredient.void_reserve.description"),
         ingredient_profile: void_reserve_profile,
     };
 
     vec![
-        (
-            synth_vapor,
-            synth_vapor_sprite,
-            Transform::from_xyz(-480.0, 160.0, 1.0),
-        ),
-        (
-            void_reserve,
-            void_reserve_sprite,
-            Transform::from_xyz(-280.0, 160.0, 1.0),
-        ),
-        (
-            circuit_juice,
-            circuit_juice_sprite,
-            Transform::from_xyz(-80.0, 160.0, 1.0),
-        ),
-        (
-            fizzion_mist,
-            fizzion_mist_sprite,
-            Transform::from_xyz(-480.0, 10.0, 1.0),
-        ),
-        (
-            sweetflux,
-            sweetflux_sprite,
-            Transform::from_xyz(-280.0, 10.0, 1.0),
-        ),
-        (
-            citraplasm,
-            citraplasm_sprite,
-            Transform::from_xyz(-80.0, 10.0, 1.0),
-        ),
+        (synth_vapor, synth_vapor_sprite),
+        (void_reserve, void_reserve_sprite),
+        (circuit_juice, circuit_juice_sprite),
+        (fizzion_mist, fizzion_mist_sprite),
+        (sweetflux, sweetflux_sprite),
+        (citraplasm, citraplasm_sprite),
     ]
 }
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::engine::settings::GameSettings;
+
+/// String tables are compiled in so every build ships the same catalogue.
+const STRING_TABLES: [(Locale, &str); 2] = [
+    (
+        Locale::English,
+        include_str!("../../assets/locales/en-US.strings"),
+    ),
+    (
+        Locale::German,
+        include_str!("../../assets/locales/de-DE.strings"),
+    ),
+];
+
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        // Settings are inserted before the plugins, so the first screen is
+        // already in the saved language.
+        let locale = app
+            .world()
+            .get_resource::<GameSettings>()
+            .map(|settings| settings.locale)
+            .unwrap_or_default();
+        app.insert_resource(Localization::load(locale))
+            .add_systems(Startup, report_missing_keys)
+            .add_systems(
+                Update,
+                refresh_localized_text.run_if(resource_changed::<Localization>),
+            );
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    English,
+    German,
+}
+
+impl Locale {
+    pub const BASE: Locale = Locale::English;
+    pub const ALL: [Locale; 2] = [Locale::English, Locale::German];
+
+    /// BCP 47 tag, shared with the Yarn string tables.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en-US",
+            Locale::German => "de-DE",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::German => "Deutsch",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Locale> {
+        Locale::ALL.into_iter().find(|locale| locale.code() == code)
+    }
+
+    pub fn next(&self) -> Locale {
+        let index = Locale::ALL.iter().position(|l| l == self).unwrap_or(0);
+        Locale::ALL[(index + 1) % Locale::ALL.len()]
+    }
+}
+
+/// Player-facing text outside of Yarn, keyed like `crafting.craft`.
+#[derive(Resource)]
+pub struct Localization {
+    pub locale: Locale,
+    tables: HashMap<Locale, HashMap<String, String>>,
+}
+
+impl Localization {
+    pub fn load(locale: Locale) -> Self {
+        let tables = STRING_TABLES
+            .iter()
+            .map(|(locale, source)| (*locale, parse_string_table(source)))
+            .collect();
+        Self { locale, tables }
+    }
+
+    /// Falls back to the base locale, then to the key itself.
+    pub fn get(&self, key: &str) -> String {
+        [self.locale, Locale::BASE]
+            .iter()
+            .find_map(|locale| self.tables.get(locale)?.get(key))
+            .cloned()
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    pub fn missing_keys(&self, locale: Locale) -> Vec<&str> {
+        let Some(base) = self.tables.get(&Locale::BASE) else {
+            return Vec::new();
+        };
+        let table = self.tables.get(&locale);
+        let mut missing: Vec<&str> = base
+            .keys()
+            .filter(|key| !table.is_some_and(|table| table.contains_key(*key)))
+            .map(String::as_str)
+            .collect();
+        missing.sort();
+        missing
+    }
+}
+
+/// Missing entries fall back to English silently, so list them once at startup.
+/// Yarn lines are checked by `dialogue_translations_cover_every_line` instead.
+fn report_missing_keys(localization: Res<Localization>) {
+    for locale in Locale::ALL {
+        let missing = localization.missing_keys(locale);
+        if !missing.is_empty() {
+            warn!(
+                "Locale {} is missing {} keys: {:?}",
+                locale.code(),
+                missing.len(),
+                missing
+            );
+        }
+    }
+}
+
+/// Text that shows a string table entry and follows the language setting.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct LocalizedText(pub &'static str);
+
+fn refresh_localized_text(
+    localization: Res<Localization>,
+    mut text_query: Query<(&mut Text, &LocalizedText)>,
+) {
+    for (mut text, localized) in text_query.iter_mut() {
+        text.0 = localization.get(localized.0);
+    }
+}
+
+fn parse_string_table(source: &str) -> HashMap<String, String> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            Some((key.trim().to_string(), value.trim().replace("\\n", "\n")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, fs, path::Path};
+
+    use super::*;
+
+    fn keys(source: &str) -> BTreeSet<String> {
+        parse_string_table(source).into_keys().collect()
+    }
+
+    #[test]
+    fn string_tables_define_the_same_keys() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/locales");
+        let base = keys(
+            &fs::read_to_string(dir.join(format!("{}.strings", Locale::BASE.code()))).unwrap(),
+        );
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            let table = keys(&fs::read_to_string(&path).unwrap());
+            let missing: Vec<_> = base.difference(&table).collect();
+            let extra: Vec<_> = table.difference(&base).collect();
+            assert!(
+                missing.is_empty() && extra.is_empty(),
+                "{}: missing {:?}, not in the base table {:?}",
+                path.display(),
+                missing,
+                extra
+            );
+        }
+    }
+
+    #[test]
+    fn every_locale_has_a_string_table() {
+        for locale in Locale::ALL {
+            assert!(
+                STRING_TABLES.iter().any(|(table, _)| *table == locale),
+                "{:?}",
+                locale
+            );
+        }
+    }
+
+    /// Yarn lines without a translation fall back to English mid-conversation.
+    #[test]
+    fn dialogue_translations_cover_every_line() {
+        let dialogue = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/dialogue");
+        let script = fs::read_to_string(dialogue.join("on_the_rocks.yarn")).unwrap();
+        let line_ids: BTreeSet<&str> = script
+            .split_whitespace()
+            .filter_map(|word| word.strip_prefix('#'))
+            .filter(|tag| tag.starts_with("line:"))
+            .collect();
+        assert!(!line_ids.is_empty(), "on_the_rocks.yarn has no #line: IDs");
+        for locale in Locale::ALL.iter().filter(|locale| **locale != Locale::BASE) {
+            let path = dialogue.join(format!("{}.strings.csv", locale.code()));
+            let table = fs::read_to_string(&path).unwrap();
+            // Line IDs never contain commas, so the second column splits cleanly.
+            let translated: BTreeSet<&str> = table
+                .lines()
+                .skip(1)
+                .filter_map(|row| row.split(',').nth(1))
+                .collect();
+            let missing: Vec<_> = line_ids.difference(&translated).collect();
+            assert!(
+                missing.is_empty(),
+                "{}: missing {:?}",
+                path.display(),
+                missing
+            );
+        }
+    }
+}
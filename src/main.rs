@@ -1,6 +1,11 @@
 use bevy::prelude::*;
 
-use crate::engine::{GameState, game_runner::GameRunnerPlugin};
+use crate::engine::{
+    GameState,
+    game_runner::GameRunnerPlugin,
+    launch::{LaunchOptions, USAGE},
+    settings::GameSettings,
+};
 
 pub mod animation;
 pub mod bar;
@@ -9,22 +14,88 @@ pub mod customers;
 pub mod dialogues;
 pub mod engine;
 pub mod ingredients;
+pub mod localization;
 pub mod ui;
 
+#[cfg(not(target_arch = "wasm32"))]
 fn main() {
+    run_game();
+}
+
+/// Browsers keep audio silent until the player interacts with the page, and the
+/// audio context opens while the plugins are added, so the game starts on the
+/// first click.
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    use wasm_bindgen::JsCast;
+
+    let window = web_sys::window().expect("the web build runs in a browser window");
+    let options = web_sys::AddEventListenerOptions::new();
+    options.set_once(true);
+    let start = wasm_bindgen::closure::Closure::once_into_js(run_game);
+    window
+        .add_event_listener_with_callback_and_add_event_listener_options(
+            "click",
+            start.unchecked_ref(),
+            &options,
+        )
+        .expect("failed to wait for a click on the page");
+}
+
+fn run_game() {
+    let launch_options = parse_launch_options();
+    let settings = GameSettings::load();
+    let window_plugin = create_window_plugin(&launch_options.display_settings(&settings));
     let mut app = App::new();
-    app.add_plugins((DefaultPlugins.set(create_window_plugin()), GameRunnerPlugin))
+    // Inserted before the plugins, which read them to decide where loading
+    // leads and which language the first screen is in.
+    app.insert_resource(launch_options)
+        .insert_resource(settings)
+        .add_plugins((
+            DefaultPlugins.set(window_plugin).set(create_asset_plugin()),
+            GameRunnerPlugin,
+        ))
         .init_state::<GameState>()
         .insert_resource(ClearColor(Color::srgb(0.05, 0.05, 0.1)))
         .run();
 }
 
-fn create_window_plugin() -> WindowPlugin {
+fn parse_launch_options() -> LaunchOptions {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{USAGE}");
+        std::process::exit(0);
+    }
+    LaunchOptions::parse(args).unwrap_or_else(|err| {
+        eprintln!("{err}\n\n{USAGE}");
+        std::process::exit(2);
+    })
+}
+
+fn create_window_plugin(settings: &GameSettings) -> WindowPlugin {
     WindowPlugin {
         primary_window: Some(Window {
             title: "Cosmos on the Rocks".to_string(),
+            mode: settings.window_mode(),
+            resolution: settings.window_resolution(),
+            present_mode: settings.present_mode(),
+            // Draw into the page's canvas and let the page size it.
+            #[cfg(target_arch = "wasm32")]
+            canvas: Some("#game".to_string()),
+            #[cfg(target_arch = "wasm32")]
+            fit_canvas_to_parent: true,
             ..default()
         }),
         ..default()
     }
 }
+
+fn create_asset_plugin() -> AssetPlugin {
+    AssetPlugin {
+        // Static web hosts answer requests for missing `.meta` files with an
+        // error page, which then fails to parse. The game ships none.
+        #[cfg(target_arch = "wasm32")]
+        meta_check: bevy::asset::AssetMetaCheck::Never,
+        ..default()
+    }
+}
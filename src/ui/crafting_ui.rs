@@ -1,13 +1,33 @@
 use crate::{
     bar::{
-        crafting::OnCraftingScreen,
+        crafting::{CraftingEvent, OnCraftingScreen},
         drinks::{Drink, spawn_crafted_drink},
         glass::Glass,
+        technique::Technique,
     },
-    constants::{BUTTON_BORDER, HOVERED_BUTTON, NORMAL_BUTTON, TEXT_COLOR},
     engine::asset_loader::ImageAssets,
+    ingredients::{Ingredient, IngredientId},
+    localization::Localization,
+    ui::{
+        drink_card::{DrinkCard, spawn_drink_card},
+        ingredient_tooltip::{
+            effect_color, secondary_effect_color, taste_color, temperature_color,
+        },
+        technique_game::TechniqueButton,
+        widgets::{ButtonSize, LabelSize, PressedButtons, UiTheme},
+    },
 };
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use std::collections::HashMap;
+
+const GLASS_PANEL_WIDTH: f32 = 420.0;
+const BAR_LABEL_WIDTH: f32 = 150.0;
+const BAR_VALUE_WIDTH: f32 = 80.0;
+const BAR_HEIGHT: f32 = 12.0;
+const THERMOMETER_MIN: f32 = -15.0;
+const THERMOMETER_MAX: f32 = 30.0;
+const REACTION_CHIP: Color = Color::srgb(0.45, 0.20, 0.70);
+const BAR_TRACK: Color = Color::srgba(1.0, 1.0, 1.0, 0.12);
 
 #[derive(Component)]
 pub enum CraftingButtons {
@@ -21,49 +41,301 @@ pub struct GlassDetailsUI;
 #[derive(Component)]
 pub struct DrinkSprite;
 
-pub fn setup_glass_ui(
+pub fn setup_glass_ui(mut commands: Commands, theme: Res<UiTheme>) {
+    commands.spawn((
+        theme.panel(Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            right: Val::Px(20.0),
+            width: Val::Px(GLASS_PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(6.0),
+            ..default()
+        }),
+        OnCraftingScreen,
+        GlassDetailsUI,
+    ));
+}
+
+/// Rebuilds the glass panel whenever the glass changes: fill level, taste and
+/// effect shares, secondary effect thresholds and the drink it would make.
+pub fn update_glass_ui(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    query: Query<&Glass>,
+    glass_query: Query<&Glass, Changed<Glass>>,
+    ingredient_query: Query<&Ingredient>,
+    panel_query: Query<Entity, With<GlassDetailsUI>>,
+    theme: Res<UiTheme>,
+    localization: Res<Localization>,
 ) {
-    let menu_font = asset_server.load("fonts/Nasa21.ttf");
-    info!("Crafting Menu Font Loaded: {:?}", menu_font);
-    if let Some(glass) = query.iter().next() {
-        info!("Crafting Menu Glass: {:#?}", glass);
-        commands.spawn((
-            Text::new(format!(
-                "Volume: {:.1} / {:.1}\nTaste: {:#?}\nEffects: {:#?}",
-                glass.get_current_volume(),
-                glass.capacity,
-                glass.taste,
-                glass.effect,
-            )),
-            Transform::from_translation(Vec3::new(0.0, 200.0, 0.0)),
-            BorderColor(Color::srgb(0.3, 0.2, 0.1)),
-            BorderRadius::ZERO,
-            BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
-            TextFont {
-                font: menu_font.clone(),
-                font_size: 30.0,
-                ..default()
+    let Some(glass) = glass_query.iter().next() else {
+        return;
+    };
+    for panel in panel_query.iter() {
+        commands
+            .entity(panel)
+            .despawn_related::<Children>()
+            .with_children(|parent| {
+                spawn_glass_details(parent, glass, &ingredient_query, &theme, &localization);
+            });
+    }
+}
+
+fn spawn_glass_details(
+    parent: &mut ChildSpawnerCommands,
+    glass: &Glass,
+    ingredient_query: &Query<&Ingredient>,
+    theme: &UiTheme,
+    localization: &Localization,
+) {
+    let volume = glass.get_current_volume();
+    spawn_bar(
+        parent,
+        theme,
+        BarRow {
+            label: localization.get("tooltip.volume"),
+            value: format!("{:.0} / {:.0}", volume, glass.capacity),
+            fraction: volume / glass.capacity,
+            color: theme.text,
+            marker: None,
+        },
+    );
+    if volume <= 0.0 {
+        parent.spawn(theme.label(localization.get("glass.empty"), LabelSize::Small));
+        return;
+    }
+
+    // The marker shows where the drink it would become is best served.
+    let preview = Drink::from(glass.clone());
+    let ideal = preview.created_drink.ideal_temperature();
+    spawn_bar(
+        parent,
+        theme,
+        BarRow {
+            label: localization.get("glass.temperature"),
+            value: format!("{:.0}°C", glass.temperature),
+            fraction: temperature_fraction(glass.temperature),
+            color: temperature_color(glass.temperature),
+            marker: Some(temperature_fraction(ideal)),
+        },
+    );
+
+    parent.spawn(section_heading(theme, localization.get("glass.tastes")));
+    for (taste, amount) in sorted_by_amount(&glass.taste) {
+        spawn_bar(
+            parent,
+            theme,
+            BarRow {
+                label: localization.get(taste.label_key()),
+                value: format!("{:.0}%", amount / volume * 100.0),
+                fraction: amount / volume,
+                color: taste_color(taste),
+                marker: None,
             },
-            TextColor(Color::srgb(1.0, 1.0, 1.0)),
-            Node {
-                position_type: PositionType::Absolute,
-                bottom: Val::Px(20.0),
-                right: Val::Px(20.0),
-                align_items: AlignItems::FlexStart,
-                justify_content: JustifyContent::FlexStart,
-                ..Default::default()
+        );
+    }
+
+    // Shares of the effects themselves: a catalyst can push an effect past
+    // the volume poured.
+    parent.spawn(section_heading(theme, localization.get("glass.effects")));
+    let effect_total = glass.effect.values().sum::<f32>().max(f32::EPSILON);
+    for (effect, amount) in sorted_by_amount(&glass.effect) {
+        spawn_bar(
+            parent,
+            theme,
+            BarRow {
+                label: localization.get(effect.label_key()),
+                value: format!("{:.0}%", amount / effect_total * 100.0),
+                fraction: amount / effect_total,
+                color: effect_color(effect),
+                marker: None,
             },
-            OnCraftingScreen,
-            GlassDetailsUI,
-        ));
+        );
+    }
+
+    // Secondary effects build up per ingredient, measured against the glass.
+    let mut secondary: Vec<(&Ingredient, f32)> = glass
+        .ingredients
+        .iter()
+        .filter_map(|(entity, amount)| Some((ingredient_query.get(*entity).ok()?, *amount)))
+        .collect();
+    secondary.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.0.name.cmp(&b.0.name))
+    });
+    let poured: Vec<IngredientId> = glass
+        .ingredients
+        .keys()
+        .filter_map(|entity| Some(ingredient_query.get(*entity).ok()?.id))
+        .collect();
+    parent.spawn(section_heading(theme, localization.get("glass.secondary")));
+    for (ingredient, amount) in secondary {
+        let effect = &ingredient.ingredient_profile.secondary_effect;
+        let needed = effect.condition().volume_needed;
+        let label = match effect.condition().catalyst {
+            Some(catalyst) if !poured.contains(&catalyst) => format!(
+                "{} ({} {})",
+                localization.get(effect.label_key()),
+                localization.get("tooltip.catalyst"),
+                localization.get(catalyst.name_key())
+            ),
+            _ => localization.get(effect.label_key()),
+        };
+        spawn_bar(
+            parent,
+            theme,
+            BarRow {
+                label,
+                value: format!("{:.0} / {:.0}", amount, needed),
+                fraction: amount / glass.capacity,
+                color: secondary_effect_color(effect),
+                marker: Some(needed / glass.capacity),
+            },
+        );
+    }
+
+    if !glass.reactions.is_empty() {
+        parent.spawn(section_heading(theme, localization.get("glass.reactions")));
+        parent
+            .spawn(Node {
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(6.0),
+                row_gap: Val::Px(6.0),
+                ..default()
+            })
+            .with_children(|parent| {
+                for reaction in &glass.reactions {
+                    parent.spawn(theme.chip(localization.get(reaction.label_key()), REACTION_CHIP));
+                }
+            });
     }
+
+    parent.spawn((
+        Text::new(format!(
+            "{}: {}",
+            localization.get("glass.preview"),
+            preview.name
+        )),
+        theme.text_font(LabelSize::Body),
+        TextColor(theme.text_light),
+        Node {
+            margin: UiRect::top(Val::Px(6.0)),
+            ..default()
+        },
+    ));
+}
+
+/// Where a temperature sits on the glass panel's thermometer.
+fn temperature_fraction(temperature: f32) -> f32 {
+    ((temperature - THERMOMETER_MIN) / (THERMOMETER_MAX - THERMOMETER_MIN)).clamp(0.0, 1.0)
+}
+
+/// Largest share first; ties keep a stable order instead of `HashMap` order.
+fn sorted_by_amount<K: Copy + std::fmt::Debug>(amounts: &HashMap<K, f32>) -> Vec<(K, f32)> {
+    let mut sorted: Vec<(K, f32)> = amounts
+        .iter()
+        .map(|(key, amount)| (*key, *amount))
+        .collect();
+    sorted.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| format!("{:?}", a.0).cmp(&format!("{:?}", b.0)))
+    });
+    sorted
+}
+
+fn section_heading(theme: &UiTheme, text: String) -> impl Bundle {
+    (
+        Text::new(text),
+        theme.text_font(LabelSize::Small),
+        TextColor(theme.text_light),
+        Node {
+            margin: UiRect::top(Val::Px(4.0)),
+            ..default()
+        },
+    )
+}
+
+pub struct BarRow {
+    pub label: String,
+    pub value: String,
+    pub fraction: f32,
+    pub color: Color,
+    /// Threshold drawn as a tick across the bar, as a fraction of its length.
+    pub marker: Option<f32>,
 }
 
-pub fn setup_crafting_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let menu_font = asset_server.load("fonts/Nasa21.ttf");
+pub fn spawn_bar(parent: &mut ChildSpawnerCommands, theme: &UiTheme, row: BarRow) {
+    parent
+        .spawn(Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                theme.label(row.label, LabelSize::Small),
+                Node {
+                    width: Val::Px(BAR_LABEL_WIDTH),
+                    ..default()
+                },
+            ));
+            parent
+                .spawn((
+                    Node {
+                        flex_grow: 1.0,
+                        height: Val::Px(BAR_HEIGHT),
+                        ..default()
+                    },
+                    BorderRadius::all(Val::Px(4.0)),
+                    BackgroundColor(BAR_TRACK),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Node {
+                            width: Val::Percent(row.fraction.clamp(0.0, 1.0) * 100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BorderRadius::all(Val::Px(4.0)),
+                        BackgroundColor(row.color),
+                    ));
+                    if let Some(marker) = row.marker {
+                        parent.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Percent(marker.clamp(0.0, 1.0) * 100.0),
+                                width: Val::Px(2.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(theme.text_light),
+                        ));
+                    }
+                });
+            parent.spawn((
+                theme.label(row.value, LabelSize::Small),
+                TextLayout::new_with_justify(JustifyText::Right),
+                Node {
+                    width: Val::Px(BAR_VALUE_WIDTH),
+                    ..default()
+                },
+            ));
+        });
+}
+
+pub fn setup_crafting_menu(
+    mut commands: Commands,
+    localization: Res<Localization>,
+    theme: Res<UiTheme>,
+) {
+    let row = Node {
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(20.0),
+        ..Default::default()
+    };
     commands
         .spawn((
             Node {
@@ -72,115 +344,102 @@ pub fn setup_crafting_menu(mut commands: Commands, asset_server: Res<AssetServer
                 justify_self: JustifySelf::Center,
                 position_type: PositionType::Relative,
                 flex_wrap: FlexWrap::NoWrap,
-                flex_direction: FlexDirection::Row,
+                flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
-                column_gap: Val::Px(20.0),
+                row_gap: Val::Px(12.0),
                 margin: UiRect::bottom(Val::Px(50.0)),
                 ..Default::default()
             },
             OnCraftingScreen,
         ))
         .with_children(|parent| {
-            // Craft Button
-            parent
-                .spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(150.0),
-                        height: Val::Px(50.0),
-                        border: UiRect::all(Val::Px(5.0)),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    BorderColor(BUTTON_BORDER),
-                    BorderRadius::MAX,
-                    BackgroundColor(NORMAL_BUTTON),
-                ))
-                .insert(CraftingButtons::Craft)
-                .with_children(|parent| {
-                    parent.spawn((
-                        Text::from("Craft"),
-                        TextFont {
-                            font: menu_font.clone(),
-                            font_size: 24.0,
-                            ..default()
-                        },
-                        TextColor(TEXT_COLOR),
+            parent.spawn(row.clone()).with_children(|techniques| {
+                for technique in Technique::ALL {
+                    techniques.spawn((
+                        theme.labelled_button(
+                            ButtonSize::Medium,
+                            localization.get(technique.label_key()),
+                        ),
+                        TechniqueButton(technique),
                     ));
-                });
-
-            // Reset Button
-            parent
-                .spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(150.0),
-                        height: Val::Px(50.0),
-                        border: UiRect::all(Val::Px(5.0)),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    BorderColor(BUTTON_BORDER),
-                    BorderRadius::MAX,
-                    BackgroundColor(NORMAL_BUTTON),
-                ))
-                .insert(CraftingButtons::Reset)
-                .with_children(|parent| {
-                    parent.spawn((
-                        Text::from("Reset"),
-                        TextFont {
-                            font: menu_font.clone(),
-                            font_size: 24.0,
-                            ..default()
-                        },
-                        TextColor(TEXT_COLOR),
-                    ));
-                });
+                }
+            });
+            parent.spawn((
+                row,
+                children![
+                    (
+                        theme.labelled_button(
+                            ButtonSize::Medium,
+                            localization.get("crafting.craft")
+                        ),
+                        CraftingButtons::Craft,
+                    ),
+                    (
+                        theme.labelled_button(
+                            ButtonSize::Medium,
+                            localization.get("crafting.reset")
+                        ),
+                        CraftingButtons::Reset,
+                    ),
+                ],
+            ));
         });
 }
 
+/// The drink and its grade card, cleared together on craft and reset.
+type CraftedDrinkFilter = Or<(With<Drink>, With<DrinkCard>)>;
+
+/// The crafted drink on the counter and its grade card.
+#[derive(SystemParam)]
+pub struct ServedDrink<'w, 's> {
+    commands: Commands<'w, 's>,
+    drink_query: Query<'w, 's, Entity, CraftedDrinkFilter>,
+    drink_sprite_query: Query<'w, 's, Entity, With<DrinkSprite>>,
+    image_assets: Res<'w, ImageAssets>,
+    theme: Res<'w, UiTheme>,
+    localization: Res<'w, Localization>,
+}
+
+impl ServedDrink<'_, '_> {
+    /// Replaces whatever was served before.
+    fn serve(&mut self, drink: Drink) {
+        for entity in self.drink_query.iter() {
+            self.commands.entity(entity).despawn();
+        }
+        info!("Crafted {:#?}", drink);
+        spawn_drink_card(&mut self.commands, &drink, &self.theme, &self.localization);
+        spawn_crafted_drink(&mut self.commands, drink, &self.image_assets);
+    }
+
+    fn clear(&mut self) {
+        for entity in self.drink_sprite_query.iter() {
+            self.commands.entity(entity).despawn();
+        }
+    }
+}
+
 pub fn crafting_button_interaction_system(
-    mut commands: Commands,
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &CraftingButtons),
-        (Changed<Interaction>, With<Button>),
-    >,
+    pressed: PressedButtons<CraftingButtons>,
+    mut crafting_events: EventWriter<CraftingEvent>,
     mut glass_query: Query<&mut Glass>,
-    drink_query: Query<Entity, With<Drink>>,
-    drink_sprite_query: Query<Entity, With<DrinkSprite>>,
-    image_assets: Res<ImageAssets>,
+    mut served: ServedDrink,
 ) {
-    for (interaction, mut color, button) in interaction_query.iter_mut() {
-        match *interaction {
-            Interaction::Pressed => match button {
-                CraftingButtons::Craft => {
-                    for entity in drink_query.iter() {
-                        commands.entity(entity).despawn();
-                    }
-                    for glass in glass_query.iter_mut() {
-                        let drink = Drink::from(glass.clone());
-                        info!("Crafted {:#?}", drink);
-                        spawn_crafted_drink(&mut commands, drink, &image_assets);
-                    }
+    for button in pressed.iter() {
+        match button {
+            CraftingButtons::Craft => {
+                for glass in glass_query.iter_mut() {
+                    served.serve(Drink::from(glass.clone()));
                 }
-                CraftingButtons::Reset => {
-                    for entity in drink_sprite_query.iter() {
-                        commands.entity(entity).despawn();
-                    }
-                    for mut glass in glass_query.iter_mut() {
-                        glass.reset();
-                        info!("Glass Reset {:#?}", glass);
-                    }
-                }
-            },
-            Interaction::Hovered => {
-                *color = HOVERED_BUTTON.into();
+                crafting_events.write(CraftingEvent::Crafted);
             }
-            Interaction::None => {
-                *color = NORMAL_BUTTON.into();
+            CraftingButtons::Reset => {
+                served.clear();
+                for mut glass in glass_query.iter_mut() {
+                    glass.reset();
+                    info!("Glass Reset {:#?}", glass);
+                }
+                crafting_events.write(CraftingEvent::Reset);
             }
         }
     }
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+use crate::{
+    bar::{crafting::OnCraftingScreen, drinks::Drink, quality::MAX_STARS},
+    localization::Localization,
+    ui::{
+        crafting_ui::{BarRow, DrinkSprite, spawn_bar},
+        widgets::{LabelSize, UiTheme},
+    },
+};
+
+const CARD_WIDTH: f32 = 360.0;
+const STAR_SIZE: f32 = 22.0;
+const STAR_FILLED: Color = Color::srgb(1.0, 0.8, 0.2);
+const STAR_EMPTY: Color = Color::srgba(1.0, 1.0, 1.0, 0.15);
+const QUALITY_BAR: Color = Color::srgb(0.3, 0.75, 0.55);
+
+/// Summary of the drink just crafted: its name, star grade and what went into the grade.
+#[derive(Component)]
+pub struct DrinkCard;
+
+pub fn spawn_drink_card(
+    commands: &mut Commands,
+    drink: &Drink,
+    theme: &UiTheme,
+    localization: &Localization,
+) {
+    let quality = drink.quality;
+    let stars = quality.stars();
+    commands
+        .spawn((
+            theme.panel(Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(40.0),
+                right: Val::Px(40.0),
+                width: Val::Px(CARD_WIDTH),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                ..default()
+            }),
+            DrinkCard,
+            // Leaves with the served drink, or with the counter if it is never served.
+            DrinkSprite,
+            OnCraftingScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(drink.name.clone()),
+                theme.text_font(LabelSize::Large),
+                TextColor(theme.text_light),
+            ));
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(6.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for star in 0..MAX_STARS {
+                        parent.spawn((
+                            Node {
+                                width: Val::Px(STAR_SIZE),
+                                height: Val::Px(STAR_SIZE),
+                                ..default()
+                            },
+                            BorderRadius::all(Val::Px(4.0)),
+                            BackgroundColor(if star < stars {
+                                STAR_FILLED
+                            } else {
+                                STAR_EMPTY
+                            }),
+                        ));
+                    }
+                });
+            for (key, score) in [
+                ("quality.fill", quality.fill),
+                ("quality.balance", quality.balance),
+                ("quality.technique", quality.technique),
+                ("quality.temperature", quality.temperature),
+            ] {
+                spawn_bar(
+                    parent,
+                    theme,
+                    BarRow {
+                        label: localization.get(key),
+                        value: format!("{:.0}%", score * 100.0),
+                        fraction: score,
+                        color: QUALITY_BAR,
+                        marker: None,
+                    },
+                );
+            }
+            parent.spawn(theme.label(
+                format!(
+                    "{} {:.1}°C",
+                    localization.get(drink.technique.label_key()),
+                    drink.temperature
+                ),
+                LabelSize::Small,
+            ));
+        });
+}
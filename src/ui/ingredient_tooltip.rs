@@ -1,31 +1,98 @@
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*, window::PrimaryWindow};
 
 use crate::{
-    bar::glass::Glass,
-    constants::{BUTTON_BORDER, NORMAL_BUTTON, TEXT_COLOR},
-    ingredients::Ingredient,
-    ui::crafting_ui::GlassDetailsUI,
+    animation::tween::{Ease, Tween, TweenTarget},
+    bar::{
+        crafting::{CraftingEvent, OnCraftingScreen},
+        glass::Glass,
+        reactions::{Reaction, pour},
+        temperature::{ROOM_TEMPERATURE, mix_temperature},
+    },
+    engine::game_runner::MainGameCamera,
+    ingredients::{Ingredient, IngredientTaste, PrimaryEffect, RestScale, SecondaryEffect},
+    localization::Localization,
+    ui::widgets::{LabelSize, UiTheme},
 };
 
+/// Gap between the hovered bottle and its tooltip.
+const TOOLTIP_GAP: f32 = 12.0;
+/// Closest the tooltip gets to the window edge.
+const TOOLTIP_MARGIN: f32 = 8.0;
+const TOOLTIP_WIDTH: f32 = 380.0;
+const BOTTLE_TIP_SCALE: f32 = 1.2;
+const BOTTLE_SNAP_SECONDS: f32 = 0.5;
+
+/// Tooltip for the ingredient it is anchored to.
 #[derive(Component)]
-pub struct IngredientTooltip;
+pub struct IngredientTooltip {
+    pub ingredient: Entity,
+}
+
+/// Everything a pour shows and tells the rest of the game.
+#[derive(SystemParam)]
+pub struct PourFeedback<'w, 's> {
+    commands: Commands<'w, 's>,
+    crafting_events: EventWriter<'w, CraftingEvent>,
+    rest_scale_query: Query<'w, 's, &'static RestScale>,
+    localization: Res<'w, Localization>,
+    theme: Res<'w, UiTheme>,
+}
+
+impl PourFeedback<'_, '_> {
+    fn poured(&mut self, ingredient_entity: Entity) {
+        self.crafting_events.write(CraftingEvent::Poured);
+        // The bottle springs back after being tipped.
+        let rest_scale = self
+            .rest_scale_query
+            .get(ingredient_entity)
+            .map_or(Vec3::ONE, |rest| rest.0);
+        self.commands.entity(ingredient_entity).insert(Tween::new(
+            TweenTarget::Scale {
+                from: rest_scale * BOTTLE_TIP_SCALE,
+                to: rest_scale,
+            },
+            BOTTLE_SNAP_SECONDS,
+            Ease::ElasticOut,
+        ));
+    }
+
+    fn reacted(&mut self, reaction: Reaction) {
+        info!("Reaction in the glass: {:?}", reaction);
+        self.crafting_events.write(CraftingEvent::Reacted(reaction));
+        self.commands
+            .spawn(
+                self.theme
+                    .toast(self.localization.get(reaction.label_key())),
+            )
+            .insert((
+                BackgroundColor(REACTION_COLOR.with_alpha(0.0)),
+                BorderColor(REACTION_BORDER.with_alpha(0.0)),
+            ));
+    }
 
-pub fn ingredient_pressed(
-    ev: Trigger<Pointer<Pressed>>,
+    fn glass_full(&mut self) {
+        info!("Glass is full, cannot add more ingredients.");
+        self.crafting_events.write(CraftingEvent::GlassFull);
+        self.commands.spawn(
+            self.theme
+                .toast(self.localization.get("crafting.glass_full")),
+        );
+    }
+}
+
+pub fn ingredient_pressed<E: Event>(
+    ev: Trigger<E>,
     mut glass_query: Query<&mut Glass>,
     ingredient_query: Query<&Ingredient>,
-    mut ui_text: Query<&mut Text, With<GlassDetailsUI>>,
-    asset_server: Res<AssetServer>,
-    mut commands: Commands,
+    mut feedback: PourFeedback,
 ) {
     let ingredient_entity = ev.target();
     for mut glass in glass_query.iter_mut() {
-        let (ingredient_size, ingredient_taste, ingredient_effect) =
+        let (ingredient_size, ingredient_temperature) =
             match ingredient_query.get(ingredient_entity) {
                 Ok(ingredient) => (
                     ingredient.ingredient_profile.size,
-                    ingredient.ingredient_profile.taste,
-                    ingredient.ingredient_profile.primary_effect,
+                    ingredient.ingredient_profile.temperature,
                 ),
                 Err(_) => {
                     warn!("Clicked entity is not an ingredient!");
@@ -34,101 +101,58 @@ pub fn ingredient_pressed(
             };
 
         if glass.get_current_volume() + ingredient_size < glass.capacity {
-            glass
-                .ingredients
-                .entry(ingredient_entity)
-                .and_modify(|v| *v += ingredient_size)
-                .or_insert(ingredient_size);
+            glass.technique = None;
+            glass.temperature = mix_temperature(
+                glass.temperature,
+                glass.get_current_volume(),
+                ingredient_temperature,
+                ingredient_size,
+            );
+            feedback.poured(ingredient_entity);
+            let reactions = pour(&mut glass, ingredient_entity, &ingredient_query);
             info!(
                 "Added ingredient {:#?} to glass with capacity {} current taste {:#?} crrent effect {:#?}",
                 glass.ingredients, glass.capacity, glass.taste, glass.effect
             );
-            glass
-                .taste
-                .entry(ingredient_taste)
-                .and_modify(|v| *v += ingredient_size)
-                .or_insert(ingredient_size);
-            glass
-                .effect
-                .entry(ingredient_effect)
-                .and_modify(|v| *v += ingredient_size)
-                .or_insert(ingredient_size);
-            for mut text in ui_text.iter_mut() {
-                text.0 = format!(
-                    "Volume: {:.1} / {:.1}\nTaste: {:#?}\nEffects: {:#?}",
-                    glass.get_current_volume(),
-                    glass.capacity,
-                    glass.taste,
-                    glass.effect,
-                );
+            for reaction in reactions {
+                feedback.reacted(reaction);
             }
         } else {
-            info!("Glass is full, cannot add more ingredients.");
-            commands.spawn((
-                GlassFullIndicator,
-                Text::new("Glass is Full!"),
-                Transform::from_translation(Vec3::new(0.0, 200.0, 0.0)),
-                BorderColor(Color::srgb(1.0, 0.0, 0.0)),
-                BorderRadius::ZERO,
-                BackgroundColor(Color::srgb(0.8, 0.1, 0.1)),
-                TextFont {
-                    font: asset_server.load("fonts/Nasa21.ttf"),
-                    font_size: 30.0,
-                    ..default()
-                },
-                TextColor(Color::srgb(1.0, 1.0, 1.0)),
-                Node {
-                    position_type: PositionType::Absolute,
-                    top: Val::Px(20.0),
-                    left: Val::Px(20.0),
-                    align_items: AlignItems::FlexStart,
-                    justify_content: JustifyContent::FlexStart,
-                    ..Default::default()
-                },
-                GlassFullTimer(Timer::from_seconds(2.0, TimerMode::Once)),
-            ));
+            feedback.glass_full();
         }
     }
 }
 
 pub fn ingredient_hover(
     ev: Trigger<Pointer<Over>>,
-    ingredient_query: Query<&Ingredient>,
-    asset_server: Res<AssetServer>,
+    ingredient_query: Query<(&Ingredient, &Sprite)>,
+    context: TooltipContext,
     mut commands: Commands,
 ) {
-    if let Ok(ingredient) = ingredient_query.get(ev.target()) {
-        commands.spawn((
-            IngredientTooltip,
-            // Accepts a `String` or any type that converts into a `String`, such as `&str`
-            Text::new(format!(
-                "{} : {}\nTaste: {:#?}\nEffects: {:#?}",
-                ingredient.name,
-                ingredient.description,
-                ingredient.ingredient_profile.taste,
-                ingredient.ingredient_profile.primary_effect
-            )),
-            Transform::from_translation(Vec3::new(400.0, 0.0, 0.0)),
-            BorderColor(BUTTON_BORDER),
-            BorderRadius::ZERO,
-            BackgroundColor(NORMAL_BUTTON),
-            TextFont {
-                font: asset_server.load("fonts/Nasa21.ttf"),
-                font_size: 20.0,
-                ..default()
+    let ingredient_entity = ev.target();
+    let Ok((ingredient, sprite)) = ingredient_query.get(ingredient_entity) else {
+        return;
+    };
+    commands
+        .spawn((
+            IngredientTooltip {
+                ingredient: ingredient_entity,
             },
-            TextColor(TEXT_COLOR),
-            // Set the style of the Node itself.
-            Node {
+            context.theme.panel(Node {
                 position_type: PositionType::Absolute,
-                top: Val::Px(20.0),
-                right: Val::Px(20.0),
-                align_items: AlignItems::FlexStart,
-                justify_content: JustifyContent::FlexStart,
-                ..Default::default()
-            },
-        ));
-    }
+                width: Val::Px(TOOLTIP_WIDTH),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                ..default()
+            }),
+            // Hidden until layout has measured it, so it never flashes at the origin.
+            Visibility::Hidden,
+            GlobalZIndex(5),
+            OnCraftingScreen,
+        ))
+        .with_children(|parent| {
+            context.spawn_contents(parent, ingredient_entity, ingredient, sprite);
+        });
 }
 
 pub fn ingredient_hover_out(
@@ -141,21 +165,363 @@ pub fn ingredient_hover_out(
     }
 }
 
-pub fn despawn_glass_full_indicator(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut query: Query<(Entity, &mut GlassFullTimer), With<GlassFullIndicator>>,
-) {
-    for (entity, mut timer) in query.iter_mut() {
-        timer.0.tick(time.delta());
-        if timer.0.finished() {
-            commands.entity(entity).despawn();
+pub fn taste_color(taste: IngredientTaste) -> Color {
+    match taste {
+        IngredientTaste::None => Color::srgb(0.35, 0.35, 0.35),
+        IngredientTaste::Sweet => Color::srgb(0.75, 0.30, 0.55),
+        IngredientTaste::Sour => Color::srgb(0.55, 0.60, 0.10),
+        IngredientTaste::Bitter => Color::srgb(0.40, 0.25, 0.15),
+        IngredientTaste::Citrus => Color::srgb(0.80, 0.55, 0.05),
+        IngredientTaste::Umami => Color::srgb(0.45, 0.30, 0.45),
+        IngredientTaste::Spicy => Color::srgb(0.75, 0.15, 0.10),
+    }
+}
+
+/// Blue below freezing, through white, to red above room temperature.
+pub fn temperature_color(temperature: f32) -> Color {
+    const COLD: Color = Color::srgb(0.25, 0.55, 0.95);
+    const NEUTRAL: Color = Color::srgb(0.85, 0.85, 0.85);
+    const WARM: Color = Color::srgb(0.90, 0.40, 0.20);
+    if temperature < 0.0 {
+        NEUTRAL.mix(&COLD, (-temperature / 12.0).min(1.0))
+    } else if temperature < ROOM_TEMPERATURE {
+        COLD.mix(&NEUTRAL, temperature / ROOM_TEMPERATURE)
+    } else {
+        NEUTRAL.mix(&WARM, ((temperature - ROOM_TEMPERATURE) / 12.0).min(1.0))
+    }
+}
+
+pub fn effect_color(effect: PrimaryEffect) -> Color {
+    match effect {
+        PrimaryEffect::Calming => Color::srgb(0.15, 0.35, 0.70),
+        PrimaryEffect::Energizing => Color::srgb(0.85, 0.45, 0.05),
+        PrimaryEffect::MindEnhancing => Color::srgb(0.45, 0.20, 0.75),
+        PrimaryEffect::CourageBoosting => Color::srgb(0.70, 0.15, 0.20),
+        PrimaryEffect::TruthInducing => Color::srgb(0.10, 0.55, 0.55),
+        PrimaryEffect::Healing => Color::srgb(0.20, 0.60, 0.25),
+    }
+}
+
+pub fn secondary_effect_color(effect: &SecondaryEffect) -> Color {
+    match effect {
+        SecondaryEffect::Euphoric(_) => Color::srgb(0.80, 0.40, 0.70),
+        SecondaryEffect::Agitated(_) => Color::srgb(0.75, 0.35, 0.10),
+        SecondaryEffect::Hallucinogenic(_) => Color::srgb(0.35, 0.65, 0.60),
+        SecondaryEffect::Paranoia(_) => Color::srgb(0.35, 0.30, 0.50),
+        SecondaryEffect::Aggresive(_) => Color::srgb(0.60, 0.10, 0.10),
+        SecondaryEffect::Sedated(_) => Color::srgb(0.25, 0.30, 0.45),
+    }
+}
+
+const HAZARD_COLOR: Color = Color::srgb(0.85, 0.10, 0.10);
+const REACTION_COLOR: Color = Color::srgb(0.45, 0.20, 0.70);
+const REACTION_BORDER: Color = Color::srgb(0.65, 0.40, 0.95);
+
+fn share(part: f32, total: f32) -> f32 {
+    if total > 0.0 {
+        part / total * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Matches the glass panel: tastes against the volume poured.
+fn taste_share(glass: &Glass, taste: IngredientTaste) -> f32 {
+    let amount = glass.taste.get(&taste).copied().unwrap_or(0.0);
+    share(amount, glass.get_current_volume())
+}
+
+/// Matches the glass panel: effects against each other, since a catalyst
+/// can push one past the volume poured.
+fn effect_share(glass: &Glass, effect: PrimaryEffect) -> f32 {
+    let amount = glass.effect.get(&effect).copied().unwrap_or(0.0);
+    share(amount, glass.effect.values().sum())
+}
+
+/// What the tooltip contents read: the glass it previews a pour into and the
+/// ingredients already in it.
+#[derive(SystemParam)]
+pub struct TooltipContext<'w, 's> {
+    all_ingredients: Query<'w, 's, &'static Ingredient>,
+    glass_query: Query<'w, 's, &'static Glass>,
+    theme: Res<'w, UiTheme>,
+    localization: Res<'w, Localization>,
+}
+
+impl TooltipContext<'_, '_> {
+    fn spawn_contents(
+        &self,
+        parent: &mut ChildSpawnerCommands,
+        ingredient_entity: Entity,
+        ingredient: &Ingredient,
+        sprite: &Sprite,
+    ) {
+        let Self {
+            all_ingredients,
+            glass_query,
+            theme,
+            localization,
+        } = self;
+        let glass = glass_query.iter().next();
+        let profile = &ingredient.ingredient_profile;
+        // Header: the bottle itself as the icon, then its name.
+        parent
+            .spawn(Node {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                ..default()
+            })
+            .with_children(|parent| {
+                parent.spawn((
+                    ImageNode {
+                        image: sprite.image.clone(),
+                        texture_atlas: sprite.texture_atlas.clone(),
+                        ..default()
+                    },
+                    Node {
+                        width: Val::Px(48.0),
+                        height: Val::Px(48.0),
+                        ..default()
+                    },
+                ));
+                parent.spawn((
+                    Text::new(ingredient.name.clone()),
+                    theme.text_font(LabelSize::Body),
+                    TextColor(theme.text_light),
+                ));
+            });
+        parent.spawn(theme.label(ingredient.description.clone(), LabelSize::Small));
+        // The request asks for the current stock here, but nothing in the game
+        // tracks how much is left in a bottle yet. The line belongs here once a
+        // stock model exists; until then the tooltip leaves it out.
+
+        parent
+            .spawn(Node {
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(6.0),
+                row_gap: Val::Px(6.0),
+                ..default()
+            })
+            .with_children(|parent| {
+                parent.spawn(theme.chip(
+                    localization.get(profile.taste.label_key()),
+                    taste_color(profile.taste),
+                ));
+                parent.spawn(theme.chip(
+                    localization.get(profile.primary_effect.label_key()),
+                    effect_color(profile.primary_effect),
+                ));
+                let condition = profile.secondary_effect.condition();
+                let requirement = match condition.catalyst {
+                    Some(catalyst) => format!(
+                        "{:.0}+, {} {}",
+                        condition.volume_needed,
+                        localization.get("tooltip.catalyst"),
+                        localization.get(catalyst.name_key())
+                    ),
+                    None => format!("{:.0}+", condition.volume_needed),
+                };
+                parent.spawn(theme.chip(
+                    format!(
+                        "{} ({})",
+                        localization.get(profile.secondary_effect.label_key()),
+                        requirement
+                    ),
+                    secondary_effect_color(&profile.secondary_effect),
+                ));
+                if let Some(hazard) = &profile.hazard {
+                    parent.spawn(theme.chip(
+                        format!("{}: {}", localization.get("tooltip.hazard"), hazard),
+                        HAZARD_COLOR,
+                    ));
+                }
+            });
+
+        let Some(glass) = glass else {
+            return;
+        };
+        parent.spawn((
+            Text::new(localization.get("tooltip.if_poured")),
+            theme.text_font(LabelSize::Body),
+            TextColor(theme.text_light),
+        ));
+        let volume = glass.get_current_volume();
+        let poured = volume + profile.size;
+        // Mirrors the check in `ingredient_pressed`.
+        if poured >= glass.capacity {
+            parent.spawn((
+                Text::new(localization.get("tooltip.overflow")),
+                theme.text_font(LabelSize::Small),
+                TextColor(HAZARD_COLOR),
+            ));
+            return;
+        }
+        let poured_temperature =
+            mix_temperature(glass.temperature, volume, profile.temperature, profile.size);
+        // Pour into a copy so the shares after include any reaction.
+        let mut after = glass.clone();
+        let reactions = pour(&mut after, ingredient_entity, all_ingredients);
+        let own_volume = glass
+            .ingredients
+            .get(&ingredient_entity)
+            .copied()
+            .unwrap_or(0.0);
+        let secondary = &profile.secondary_effect;
+        let lines = [
+            (
+                format!(
+                    "{}: {:.0} -> {:.0} / {:.0}",
+                    localization.get("tooltip.volume"),
+                    volume,
+                    poured,
+                    glass.capacity
+                ),
+                theme.text,
+            ),
+            (
+                format!(
+                    "{}: {:.0}% -> {:.0}%",
+                    localization.get(profile.taste.label_key()),
+                    taste_share(glass, profile.taste),
+                    taste_share(&after, profile.taste)
+                ),
+                taste_color(profile.taste),
+            ),
+            (
+                format!(
+                    "{}: {:.0}% -> {:.0}%",
+                    localization.get(profile.primary_effect.label_key()),
+                    effect_share(glass, profile.primary_effect),
+                    effect_share(&after, profile.primary_effect)
+                ),
+                effect_color(profile.primary_effect),
+            ),
+            (
+                format!(
+                    "{}: {:.0} -> {:.0} / {:.0}",
+                    localization.get(secondary.label_key()),
+                    own_volume,
+                    own_volume + profile.size,
+                    secondary.condition().volume_needed
+                ),
+                secondary_effect_color(secondary),
+            ),
+            (
+                format!(
+                    "{}: {:.0}°C -> {:.0}°C",
+                    localization.get("tooltip.temperature"),
+                    glass.temperature,
+                    poured_temperature
+                ),
+                temperature_color(poured_temperature),
+            ),
+        ];
+        for (text, color) in lines {
+            parent
+                .spawn(Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    // Colour swatch standing in for the category icon.
+                    parent.spawn((
+                        Node {
+                            width: Val::Px(10.0),
+                            height: Val::Px(10.0),
+                            ..default()
+                        },
+                        BorderRadius::MAX,
+                        BackgroundColor(color),
+                    ));
+                    parent.spawn(theme.label(text, LabelSize::Small));
+                });
+        }
+        for reaction in reactions {
+            parent.spawn(theme.chip(
+                format!(
+                    "{}: {}",
+                    localization.get("tooltip.reacts"),
+                    localization.get(reaction.label_key())
+                ),
+                REACTION_COLOR,
+            ));
         }
     }
 }
 
-#[derive(Component, Deref, DerefMut)]
-pub struct GlassFullTimer(pub Timer);
+/// Rebuilds open tooltips after a pour so the preview stays current.
+pub fn refresh_ingredient_tooltip(
+    mut commands: Commands,
+    tooltip_query: Query<(Entity, &IngredientTooltip)>,
+    ingredient_query: Query<(&Ingredient, &Sprite)>,
+    changed_glass: Query<(), Changed<Glass>>,
+    context: TooltipContext,
+) {
+    if changed_glass.is_empty() {
+        return;
+    }
+    for (tooltip, anchor) in tooltip_query.iter() {
+        let Ok((ingredient, sprite)) = ingredient_query.get(anchor.ingredient) else {
+            continue;
+        };
+        commands
+            .entity(tooltip)
+            .despawn_related::<Children>()
+            .with_children(|parent| {
+                context.spawn_contents(parent, anchor.ingredient, ingredient, sprite);
+            });
+    }
+}
 
-#[derive(Component)]
-pub struct GlassFullIndicator;
+/// Places the tooltip beside its bottle, flipping to the left side and
+/// clamping so it always stays inside the window.
+pub fn position_ingredient_tooltip(
+    mut tooltip_query: Query<(
+        &IngredientTooltip,
+        &mut Node,
+        &ComputedNode,
+        &mut Visibility,
+    )>,
+    sprite_query: Query<(&GlobalTransform, &Sprite)>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainGameCamera>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+) {
+    let (Ok((camera, camera_transform)), Ok(window)) =
+        (camera_query.single(), window_query.single())
+    else {
+        return;
+    };
+    // Node `Val::Px` values are in logical pixels divided by the UI scale.
+    let screen = window.size() / ui_scale.0;
+    for (tooltip, mut node, computed, mut visibility) in tooltip_query.iter_mut() {
+        let Ok((transform, sprite)) = sprite_query.get(tooltip.ingredient) else {
+            continue;
+        };
+        let size = computed.size() * computed.inverse_scale_factor();
+        if size == Vec2::ZERO {
+            continue;
+        }
+        let half =
+            sprite.custom_size.unwrap_or(Vec2::splat(128.0)) * transform.scale().truncate() / 2.0;
+        let center = transform.translation();
+        let corners = [
+            center + Vec3::new(-half.x, half.y, 0.0),
+            center + Vec3::new(half.x, half.y, 0.0),
+        ]
+        .map(|corner| camera.world_to_viewport(camera_transform, corner));
+        let [Ok(top_left), Ok(top_right)] = corners else {
+            continue;
+        };
+        let (top_left, top_right) = (top_left / ui_scale.0, top_right / ui_scale.0);
+        let mut left = top_right.x + TOOLTIP_GAP;
+        if left + size.x > screen.x - TOOLTIP_MARGIN {
+            left = top_left.x - TOOLTIP_GAP - size.x;
+        }
+        let max = (screen - size - TOOLTIP_MARGIN).max(Vec2::splat(TOOLTIP_MARGIN));
+        node.left = Val::Px(left.clamp(TOOLTIP_MARGIN, max.x));
+        node.top = Val::Px(top_right.y.clamp(TOOLTIP_MARGIN, max.y));
+        *visibility = Visibility::Inherited;
+    }
+}
@@ -0,0 +1,175 @@
+use bevy::prelude::*;
+use iyes_progress::ProgressTracker;
+
+use crate::{
+    engine::{GameState, asset_loader::LoadFailures},
+    localization::Localization,
+    ui::widgets::{ButtonSize, LabelSize, PressedButtons, UiTheme},
+};
+
+const PROGRESS_BAR_WIDTH: f32 = 480.0;
+
+#[derive(Component)]
+pub struct OnLoadingScreen;
+
+#[derive(Component)]
+pub struct OnLoadingFailedScreen;
+
+#[derive(Component)]
+pub struct LoadingProgressFill;
+
+#[derive(Component)]
+pub struct LoadingProgressText;
+
+#[derive(Component)]
+pub enum LoadingFailedButtons {
+    BackToMenu,
+    Quit,
+}
+
+pub fn setup_loading_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    localization: Res<Localization>,
+    theme: Res<UiTheme>,
+    mut failures: ResMut<LoadFailures>,
+) {
+    failures.0.clear();
+    // Loaded directly rather than from `ImageAssets`, which is what we are waiting on.
+    let title_img = asset_server.load("images/ui/name.png");
+    commands
+        .spawn((theme.backdrop(25), OnLoadingScreen))
+        .with_children(|parent| {
+            parent.spawn((
+                ImageNode {
+                    image: title_img,
+                    ..default()
+                },
+                Node {
+                    width: Val::Px(200.0),
+                    height: Val::Px(200.0),
+                    ..default()
+                },
+            ));
+            parent.spawn(theme.label(localization.get("loading.title"), LabelSize::Large));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(PROGRESS_BAR_WIDTH),
+                        height: Val::Px(18.0),
+                        border: UiRect::all(Val::Px(3.0)),
+                        ..default()
+                    },
+                    BorderColor(theme.border),
+                    BorderRadius::MAX,
+                    BackgroundColor(theme.button_normal),
+                ))
+                .with_child((
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BorderRadius::MAX,
+                    BackgroundColor(theme.text),
+                    LoadingProgressFill,
+                ));
+            parent.spawn((theme.label("0%", LabelSize::Small), LoadingProgressText));
+        });
+}
+
+pub fn update_loading_progress(
+    progress: Res<ProgressTracker<GameState>>,
+    mut fill_query: Query<&mut Node, With<LoadingProgressFill>>,
+    mut text_query: Query<&mut Text, With<LoadingProgressText>>,
+) {
+    let progress = progress.get_global_progress();
+    let fraction = if progress.total == 0 {
+        0.0
+    } else {
+        progress.done as f32 / progress.total as f32
+    };
+    for mut node in fill_query.iter_mut() {
+        node.width = Val::Percent(fraction * 100.0);
+    }
+    for mut text in text_query.iter_mut() {
+        text.0 = format!(
+            "{:.0}% ({}/{})",
+            fraction * 100.0,
+            progress.done,
+            progress.total
+        );
+    }
+}
+
+pub fn cleanup_loading_screen(mut commands: Commands, query: Query<Entity, With<OnLoadingScreen>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn setup_loading_failed_screen(
+    mut commands: Commands,
+    localization: Res<Localization>,
+    theme: Res<UiTheme>,
+    failures: Res<LoadFailures>,
+) {
+    commands
+        .spawn((theme.backdrop(25), OnLoadingFailedScreen))
+        .with_children(|parent| {
+            parent.spawn(theme.label(localization.get("loading.failed"), LabelSize::Title));
+            parent.spawn((
+                Text::new(localization.get("loading.failed_hint")),
+                theme.text_font(LabelSize::Body),
+                TextColor(theme.text_light),
+            ));
+            let paths = if failures.0.is_empty() {
+                localization.get("loading.unknown_asset")
+            } else {
+                failures.0.join("\n")
+            };
+            parent.spawn((
+                theme.panel(Node {
+                    max_width: Val::Percent(80.0),
+                    ..default()
+                }),
+                children![(
+                    Text::new(paths),
+                    theme.text_font(LabelSize::Body),
+                    TextColor(theme.toast_border),
+                )],
+            ));
+            parent.spawn((
+                theme.labelled_button(ButtonSize::Large, localization.get("loading.back")),
+                LoadingFailedButtons::BackToMenu,
+            ));
+            parent.spawn((
+                theme.labelled_button(ButtonSize::Large, localization.get("loading.quit")),
+                LoadingFailedButtons::Quit,
+            ));
+        });
+}
+
+pub fn loading_failed_button_system(
+    pressed: PressedButtons<LoadingFailedButtons>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut app_exit: EventWriter<AppExit>,
+) {
+    for button in pressed.iter() {
+        match button {
+            LoadingFailedButtons::BackToMenu => game_state.set(GameState::MainMenu),
+            LoadingFailedButtons::Quit => {
+                app_exit.write(AppExit::Success);
+            }
+        }
+    }
+}
+
+pub fn cleanup_loading_failed_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<OnLoadingFailedScreen>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,19 +1,30 @@
 use crate::{
-    constants::{BUTTON_BORDER, HOVERED_BUTTON, NORMAL_BUTTON, TEXT_COLOR},
-    engine::GameState,
+    dialogues::{DialogueState, NightVariables},
+    engine::{GameState, save_game::SaveGame},
+    localization::Localization,
+    ui::{
+        settings_menu::SettingsMenuState,
+        widgets::{ButtonSize, Disabled, PressedButtons, UiTheme},
+    },
 };
 use bevy::prelude::*;
 
 #[derive(Component)]
 pub enum MenuButtons {
+    Continue,
     Play,
+    Settings,
 }
 
 #[derive(Component)]
 pub struct OnMainMenuScreen;
 
-pub fn setup_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let menu_font = asset_server.load("fonts/Nasa21.ttf");
+pub fn setup_main_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    localization: Res<Localization>,
+    theme: Res<UiTheme>,
+) {
     let title_img = asset_server.load("images/ui/name.png");
     commands
         .spawn((
@@ -45,58 +56,50 @@ pub fn setup_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 },
             ));
-            // Start Game Button
             parent
                 .spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(250.0),
-                        height: Val::Px(65.0),
-                        border: UiRect::all(Val::Px(5.0)),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    BorderColor(BUTTON_BORDER),
-                    BorderRadius::MAX,
-                    BackgroundColor(NORMAL_BUTTON),
+                    theme.localized_button(ButtonSize::Large, "menu.continue", &localization),
+                    MenuButtons::Continue,
                 ))
-                .insert(MenuButtons::Play)
-                .with_children(|parent| {
-                    parent.spawn((
-                        Text::from("Play Game"),
-                        TextFont {
-                            font: menu_font.clone(),
-                            font_size: 30.0,
-                            ..default()
-                        },
-                        TextColor(TEXT_COLOR),
-                    ));
-                });
+                .insert(Disabled(!SaveGame::exists()));
+            parent.spawn((
+                theme.localized_button(ButtonSize::Large, "menu.play", &localization),
+                MenuButtons::Play,
+            ));
+            parent.spawn((
+                theme.localized_button(ButtonSize::Large, "menu.settings", &localization),
+                MenuButtons::Settings,
+            ));
         });
 }
 
 // System to handle button interaction
 pub fn button_interaction_system(
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &MenuButtons),
-        (Changed<Interaction>, With<Button>),
-    >,
+    pressed: PressedButtons<MenuButtons>,
     mut game_state: ResMut<NextState<GameState>>,
+    mut settings_state: ResMut<NextState<SettingsMenuState>>,
+    mut dialogue_state: ResMut<NextState<DialogueState>>,
+    mut night_variables: ResMut<NightVariables>,
 ) {
-    for (interaction, mut color, button) in interaction_query.iter_mut() {
-        match *interaction {
-            Interaction::Pressed => match button {
-                MenuButtons::Play => {
-                    println!("Play Game Button Clicked");
+    for button in pressed.iter() {
+        match button {
+            MenuButtons::Continue => match SaveGame::read() {
+                Some(save) => {
+                    info!("Continuing from {:?}", save.dialogue_state);
+                    dialogue_state.set(save.dialogue_state);
+                    night_variables.0 = save.variables;
                     game_state.set(GameState::Loading);
                 }
+                None => warn!("Save file could not be read"),
             },
-            Interaction::Hovered => {
-                *color = HOVERED_BUTTON.into();
+            MenuButtons::Play => {
+                println!("Play Game Button Clicked");
+                dialogue_state.set(DialogueState::BartenderMonologue);
+                night_variables.0.clear();
+                game_state.set(GameState::Loading);
             }
-            Interaction::None => {
-                *color = NORMAL_BUTTON.into();
+            MenuButtons::Settings => {
+                settings_state.set(SettingsMenuState::Open);
             }
         }
     }
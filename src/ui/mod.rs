@@ -1,30 +1,111 @@
 use crate::{
-    engine::GameState,
+    dialogues::history::toggle_history,
+    engine::{GameState, pause::PauseState},
     ui::{
-        crafting_ui::{crafting_button_interaction_system, setup_crafting_menu},
+        crafting_ui::{crafting_button_interaction_system, setup_crafting_menu, update_glass_ui},
+        ingredient_tooltip::{position_ingredient_tooltip, refresh_ingredient_tooltip},
+        loading_screen::{
+            cleanup_loading_failed_screen, cleanup_loading_screen, loading_failed_button_system,
+            setup_loading_failed_screen, setup_loading_screen, update_loading_progress,
+        },
         main_menu::{button_interaction_system, cleanup_menu, setup_main_menu},
+        navigation::NavigationPlugin,
+        pause_menu::{
+            cleanup_pause_menu, pause_button_interaction_system, setup_pause_menu, toggle_pause,
+        },
+        settings_menu::{
+            SettingsMenuState, close_settings_menu, settings_button_interaction_system,
+            setup_settings_menu, update_settings_text,
+        },
+        technique_game::{
+            run_technique_game, technique_button_system, update_crafting_availability,
+        },
+        widgets::WidgetsPlugin,
     },
 };
 use bevy::prelude::*;
 
 pub mod crafting_ui;
+pub mod drink_card;
 pub mod ingredient_tooltip;
+pub mod loading_screen;
 pub mod main_menu;
+pub mod navigation;
+pub mod pause_menu;
+pub mod settings_menu;
+pub mod technique_game;
+pub mod widgets;
 
 pub struct GameUiPlugin;
 
 impl Plugin for GameUiPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::MainMenu), setup_main_menu)
+        app.add_plugins((WidgetsPlugin, NavigationPlugin))
+            .init_state::<SettingsMenuState>()
+            .add_systems(OnEnter(GameState::MainMenu), setup_main_menu)
             .add_systems(
                 Update,
-                button_interaction_system.run_if(in_state(GameState::MainMenu)),
+                button_interaction_system
+                    .run_if(in_state(GameState::MainMenu).and(in_state(SettingsMenuState::Closed))),
             )
             .add_systems(OnExit(GameState::MainMenu), cleanup_menu)
+            .add_systems(OnEnter(GameState::Loading), setup_loading_screen)
+            .add_systems(
+                Update,
+                update_loading_progress.run_if(in_state(GameState::Loading)),
+            )
+            .add_systems(OnExit(GameState::Loading), cleanup_loading_screen)
+            .add_systems(
+                OnEnter(GameState::LoadingFailed),
+                setup_loading_failed_screen,
+            )
+            .add_systems(
+                Update,
+                loading_failed_button_system.run_if(in_state(GameState::LoadingFailed)),
+            )
+            .add_systems(
+                OnExit(GameState::LoadingFailed),
+                cleanup_loading_failed_screen,
+            )
             .add_systems(OnEnter(GameState::Crafting), setup_crafting_menu)
             .add_systems(
                 Update,
-                (crafting_button_interaction_system,).run_if(in_state(GameState::Crafting)),
-            );
+                (
+                    crafting_button_interaction_system,
+                    (run_technique_game, technique_button_system).chain(),
+                    update_crafting_availability,
+                )
+                    .run_if(in_state(GameState::Crafting).and(in_state(PauseState::Running))),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_glass_ui,
+                    (refresh_ingredient_tooltip, position_ingredient_tooltip).chain(),
+                )
+                    .run_if(in_state(GameState::Crafting)),
+            )
+            .add_systems(
+                Update,
+                toggle_pause.after(toggle_history).run_if(
+                    in_state(GameState::Dialogues)
+                        .or(in_state(GameState::Crafting))
+                        .and(in_state(SettingsMenuState::Closed)),
+                ),
+            )
+            .add_systems(OnEnter(PauseState::Paused), setup_pause_menu)
+            .add_systems(
+                Update,
+                pause_button_interaction_system
+                    .run_if(in_state(PauseState::Paused).and(in_state(SettingsMenuState::Closed))),
+            )
+            .add_systems(OnExit(PauseState::Paused), cleanup_pause_menu)
+            .add_systems(OnEnter(SettingsMenuState::Open), setup_settings_menu)
+            .add_systems(
+                Update,
+                (settings_button_interaction_system, update_settings_text)
+                    .run_if(in_state(SettingsMenuState::Open)),
+            )
+            .add_systems(OnExit(SettingsMenuState::Open), close_settings_menu);
     }
 }
@@ -0,0 +1,291 @@
+use bevy::{ecs::system::SystemParam, input::mouse::MouseMotion, prelude::*, ui::UiSystem};
+
+use crate::{
+    bar::{crafting::OnCraftingScreen, glass::Glass},
+    engine::{GameState, game_runner::MainGameCamera, pause::PauseState},
+    ingredients::ShelfSlot,
+    ui::crafting_ui::CraftingButtons,
+};
+
+const FOCUS_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+
+const SHELF_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavFocus>()
+            .init_resource::<NavPressed>()
+            .add_systems(
+                PreUpdate,
+                (
+                    clear_focus_on_mouse,
+                    move_focus,
+                    confirm_focus,
+                    (shelf_slot_shortcuts, crafting_gamepad_shortcuts)
+                        .run_if(in_state(GameState::Crafting).and(in_state(PauseState::Running))),
+                )
+                    .chain()
+                    .after(UiSystem::Focus),
+            )
+            .add_systems(Update, (highlight_focused_node, highlight_focused_sprite))
+            .add_systems(Last, release_nav_presses);
+    }
+}
+
+/// Sprites the player can reach with keyboard or gamepad. Every `Button` is
+/// navigable without it.
+#[derive(Component, Default)]
+pub struct Focusable;
+
+/// Sent to a focused sprite when the player confirms it, alongside its pointer observers.
+#[derive(Event, Clone, Debug)]
+pub struct NavActivate;
+
+#[derive(Resource, Default)]
+pub struct NavFocus(pub Option<Entity>);
+
+/// Buttons pressed through navigation, released again at the end of the frame.
+#[derive(Resource, Default)]
+struct NavPressed(Vec<Entity>);
+
+struct NavCandidate {
+    entity: Entity,
+    position: Vec2,
+    layer: i32,
+}
+
+fn clear_focus_on_mouse(mut motion: EventReader<MouseMotion>, mut focus: ResMut<NavFocus>) {
+    if motion.read().count() > 0 {
+        focus.0 = None;
+    }
+}
+
+/// Buttons and focusable sprites, with what is needed to place them on screen.
+#[derive(SystemParam)]
+struct NavTargets<'w, 's> {
+    buttons: Query<'w, 's, (Entity, &'static GlobalTransform, &'static ComputedNode), With<Button>>,
+    sprites: Query<'w, 's, (Entity, &'static GlobalTransform), With<Focusable>>,
+    parents: Query<'w, 's, &'static ChildOf>,
+    z_indices: Query<'w, 's, &'static GlobalZIndex>,
+    camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<MainGameCamera>>,
+}
+
+impl NavTargets<'_, '_> {
+    /// Screen positions of everything navigable on the topmost UI layer.
+    fn candidates(&self) -> Vec<NavCandidate> {
+        let Self {
+            buttons,
+            sprites,
+            parents,
+            z_indices,
+            camera,
+        } = self;
+        let mut candidates: Vec<NavCandidate> = buttons
+            .iter()
+            .map(|(entity, transform, node)| {
+                let mut root = entity;
+                while let Ok(child_of) = parents.get(root) {
+                    root = child_of.parent();
+                }
+                NavCandidate {
+                    entity,
+                    position: transform.translation().truncate() * node.inverse_scale_factor(),
+                    layer: z_indices.get(root).map(|z| z.0).unwrap_or(0),
+                }
+            })
+            .collect();
+        if let Ok((camera, camera_transform)) = camera.single() {
+            candidates.extend(sprites.iter().filter_map(|(entity, transform)| {
+                let position = camera
+                    .world_to_viewport(camera_transform, transform.translation())
+                    .ok()?;
+                Some(NavCandidate {
+                    entity,
+                    position,
+                    layer: 0,
+                })
+            }));
+        }
+        let top_layer = candidates.iter().map(|c| c.layer).max().unwrap_or(0);
+        candidates.retain(|candidate| candidate.layer == top_layer);
+        candidates
+    }
+}
+
+fn nav_direction(keys: &ButtonInput<KeyCode>, gamepads: &Query<&Gamepad>) -> Option<Vec2> {
+    let pressed = |key: KeyCode, button: GamepadButton| {
+        keys.just_pressed(key) || gamepads.iter().any(|gamepad| gamepad.just_pressed(button))
+    };
+    // Screen space, y grows downwards.
+    if pressed(KeyCode::ArrowUp, GamepadButton::DPadUp) {
+        Some(Vec2::NEG_Y)
+    } else if pressed(KeyCode::ArrowDown, GamepadButton::DPadDown) {
+        Some(Vec2::Y)
+    } else if pressed(KeyCode::ArrowLeft, GamepadButton::DPadLeft) {
+        Some(Vec2::NEG_X)
+    } else if pressed(KeyCode::ArrowRight, GamepadButton::DPadRight) {
+        Some(Vec2::X)
+    } else {
+        None
+    }
+}
+
+fn move_focus(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut focus: ResMut<NavFocus>,
+    targets: NavTargets,
+) {
+    let Some(direction) = nav_direction(&keys, &gamepads) else {
+        return;
+    };
+    let candidates = targets.candidates();
+    let current = focus
+        .0
+        .and_then(|entity| candidates.iter().find(|c| c.entity == entity));
+    let Some(current) = current else {
+        // Start from the top-left-most element when nothing is focused yet.
+        focus.0 = candidates
+            .iter()
+            .min_by(|a, b| {
+                (a.position.y, a.position.x)
+                    .partial_cmp(&(b.position.y, b.position.x))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|c| c.entity);
+        return;
+    };
+    let next = candidates
+        .iter()
+        .filter(|c| c.entity != current.entity)
+        .filter_map(|c| {
+            let delta = c.position - current.position;
+            let along = delta.dot(direction);
+            (along > 0.0).then(|| (c.entity, along + 2.0 * delta.perp_dot(direction).abs()))
+        })
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+    if let Some((entity, _)) = next {
+        focus.0 = Some(entity);
+    }
+}
+
+fn confirm_focus(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    focus: Res<NavFocus>,
+    mut interactions: Query<&mut Interaction, With<Button>>,
+    mut pressed: ResMut<NavPressed>,
+) {
+    let confirmed = keys.just_pressed(KeyCode::Enter)
+        || keys.just_pressed(KeyCode::Space)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::South));
+    let Some(entity) = focus.0.filter(|_| confirmed) else {
+        return;
+    };
+    if let Ok(mut interaction) = interactions.get_mut(entity) {
+        *interaction = Interaction::Pressed;
+        pressed.0.push(entity);
+    } else if let Ok(mut entity_commands) = commands.get_entity(entity) {
+        entity_commands.trigger(NavActivate);
+    }
+}
+
+fn shelf_slot_shortcuts(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    slots: Query<(Entity, &ShelfSlot)>,
+) {
+    for (index, key) in SHELF_KEYS.iter().enumerate() {
+        if !keys.just_pressed(*key) {
+            continue;
+        }
+        for (entity, slot) in slots.iter() {
+            if slot.0 == index {
+                commands.entity(entity).trigger(NavActivate);
+            }
+        }
+    }
+}
+
+fn crafting_gamepad_shortcuts(
+    mut commands: Commands,
+    gamepads: Query<&Gamepad>,
+    mut buttons: Query<(Entity, &mut Interaction, &CraftingButtons)>,
+    glass_query: Query<Entity, (With<Glass>, With<OnCraftingScreen>)>,
+    mut pressed: ResMut<NavPressed>,
+) {
+    let just_pressed =
+        |button: GamepadButton| gamepads.iter().any(|gamepad| gamepad.just_pressed(button));
+    for (entity, mut interaction, button) in buttons.iter_mut() {
+        let shortcut = match button {
+            CraftingButtons::Craft => GamepadButton::West,
+            CraftingButtons::Reset => GamepadButton::North,
+        };
+        if just_pressed(shortcut) {
+            *interaction = Interaction::Pressed;
+            pressed.0.push(entity);
+        }
+    }
+    if just_pressed(GamepadButton::RightTrigger) {
+        for glass in glass_query.iter() {
+            commands.entity(glass).trigger(NavActivate);
+        }
+    }
+}
+
+fn release_nav_presses(mut pressed: ResMut<NavPressed>, mut interactions: Query<&mut Interaction>) {
+    for entity in pressed.0.drain(..) {
+        if let Ok(mut interaction) = interactions.get_mut(entity) {
+            *interaction = Interaction::None;
+        }
+    }
+}
+
+fn highlight_focused_node(
+    mut commands: Commands,
+    focus: Res<NavFocus>,
+    outlined: Query<Entity, (With<Outline>, With<Button>)>,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+    for entity in outlined.iter() {
+        if Some(entity) != focus.0 {
+            commands.entity(entity).remove::<Outline>();
+        }
+    }
+    if let Some(mut entity_commands) = focus.0.and_then(|entity| commands.get_entity(entity).ok()) {
+        entity_commands.try_insert(Outline::new(Val::Px(4.0), Val::Px(2.0), FOCUS_COLOR));
+    }
+}
+
+fn highlight_focused_sprite(
+    focus: Res<NavFocus>,
+    sprites: Query<(&GlobalTransform, &Sprite), With<Focusable>>,
+    mut gizmos: Gizmos,
+) {
+    let Some((transform, sprite)) = focus.0.and_then(|entity| sprites.get(entity).ok()) else {
+        return;
+    };
+    let size = sprite.custom_size.unwrap_or(Vec2::splat(128.0)) * transform.scale().truncate();
+    gizmos.rect_2d(
+        Isometry2d::from_translation(transform.translation().truncate()),
+        size + Vec2::splat(8.0),
+        FOCUS_COLOR,
+    );
+}
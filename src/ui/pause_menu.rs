@@ -0,0 +1,129 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_yarnspinner::prelude::DialogueRunner;
+
+use crate::{
+    dialogues::{DialogueState, NightVariables, history::DialogueHistory},
+    engine::{GameState, pause::PauseState, save_game::SaveGame},
+    localization::{Localization, LocalizedText},
+    ui::{
+        crafting_ui::DrinkSprite,
+        settings_menu::SettingsMenuState,
+        widgets::{ButtonSize, LabelSize, PressedButtons, UiTheme},
+    },
+};
+
+#[derive(Component)]
+pub enum PauseButtons {
+    Resume,
+    Settings,
+    Save,
+    QuitToMenu,
+}
+
+#[derive(Component)]
+pub struct OnPauseScreen;
+
+pub fn toggle_pause(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    history: Res<DialogueHistory>,
+    pause_state: Res<State<PauseState>>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+) {
+    let pressed = keys.just_pressed(KeyCode::Escape)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::Start));
+    if !pressed || history.is_open {
+        return;
+    }
+    next_pause_state.set(match pause_state.get() {
+        PauseState::Running => PauseState::Paused,
+        PauseState::Paused => PauseState::Running,
+    });
+}
+
+pub fn setup_pause_menu(
+    mut commands: Commands,
+    localization: Res<Localization>,
+    theme: Res<UiTheme>,
+) {
+    commands
+        .spawn((theme.backdrop(15), OnPauseScreen))
+        .with_children(|parent| {
+            parent.spawn((
+                theme.label(localization.get("pause.title"), LabelSize::Title),
+                LocalizedText("pause.title"),
+            ));
+            for (button, key) in [
+                (PauseButtons::Resume, "pause.resume"),
+                (PauseButtons::Settings, "menu.settings"),
+                (PauseButtons::Save, "pause.save"),
+                (PauseButtons::QuitToMenu, "pause.quit"),
+            ] {
+                parent.spawn((
+                    theme.localized_button(ButtonSize::Large, key, &localization),
+                    button,
+                ));
+            }
+        });
+}
+
+/// States the pause menu buttons lead to.
+#[derive(SystemParam)]
+pub struct PauseTransitions<'w> {
+    pause_state: ResMut<'w, NextState<PauseState>>,
+    settings_state: ResMut<'w, NextState<SettingsMenuState>>,
+    game_state: ResMut<'w, NextState<GameState>>,
+    dialogue_state: ResMut<'w, NextState<DialogueState>>,
+}
+
+/// Where the night is, for saving from the pause menu.
+#[derive(SystemParam)]
+pub struct NightProgress<'w, 's> {
+    dialogue_state: Res<'w, State<DialogueState>>,
+    runner_query: Query<'w, 's, &'static DialogueRunner>,
+    night_variables: Res<'w, NightVariables>,
+}
+
+impl NightProgress<'_, '_> {
+    fn capture(&self) -> SaveGame {
+        SaveGame::capture(
+            self.dialogue_state.get(),
+            self.runner_query.iter().next(),
+            &self.night_variables,
+        )
+    }
+}
+
+pub fn pause_button_interaction_system(
+    mut commands: Commands,
+    pressed: PressedButtons<PauseButtons>,
+    mut transitions: PauseTransitions,
+    progress: NightProgress,
+    drink_sprite_query: Query<Entity, With<DrinkSprite>>,
+) {
+    for button in pressed.iter() {
+        match button {
+            PauseButtons::Resume => transitions.pause_state.set(PauseState::Running),
+            PauseButtons::Settings => transitions.settings_state.set(SettingsMenuState::Open),
+            PauseButtons::Save => progress.capture().write(),
+            PauseButtons::QuitToMenu => {
+                // Served drinks live outside either screen's cleanup marker.
+                for entity in drink_sprite_query.iter() {
+                    commands.entity(entity).despawn();
+                }
+                transitions
+                    .dialogue_state
+                    .set(DialogueState::BartenderMonologue);
+                transitions.game_state.set(GameState::MainMenu);
+            }
+        }
+    }
+}
+
+pub fn cleanup_pause_menu(mut commands: Commands, query: Query<Entity, With<OnPauseScreen>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+
+use crate::{
+    engine::settings::{GameSettings, RESOLUTIONS, TEXT_SPEED_RANGE, UI_SCALE_RANGE, VOLUME_RANGE},
+    localization::{Localization, LocalizedText},
+    ui::widgets::{ButtonSize, LabelSize, PressedButtons, UiTheme},
+};
+
+const VOLUME_STEP: f32 = 0.1;
+const TEXT_SPEED_STEP: f32 = 10.0;
+const UI_SCALE_STEP: f32 = 0.1;
+
+/// Overlay shared by the main menu and the pause menu.
+#[derive(States, Clone, Copy, Eq, PartialEq, Debug, Hash, Default)]
+pub enum SettingsMenuState {
+    #[default]
+    Closed,
+    Open,
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettingKind {
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    Fullscreen,
+    Resolution,
+    Vsync,
+    TextSpeed,
+    UiScale,
+    Language,
+}
+
+impl SettingKind {
+    pub const ALL: [SettingKind; 9] = [
+        SettingKind::MasterVolume,
+        SettingKind::MusicVolume,
+        SettingKind::SfxVolume,
+        SettingKind::Fullscreen,
+        SettingKind::Resolution,
+        SettingKind::Vsync,
+        SettingKind::TextSpeed,
+        SettingKind::UiScale,
+        SettingKind::Language,
+    ];
+
+    fn label_key(&self) -> &'static str {
+        match self {
+            SettingKind::MasterVolume => "settings.master_volume",
+            SettingKind::MusicVolume => "settings.music_volume",
+            SettingKind::SfxVolume => "settings.sfx_volume",
+            SettingKind::Fullscreen => "settings.fullscreen",
+            SettingKind::Resolution => "settings.resolution",
+            SettingKind::Vsync => "settings.vsync",
+            SettingKind::TextSpeed => "settings.text_speed",
+            SettingKind::UiScale => "settings.ui_scale",
+            SettingKind::Language => "settings.language",
+        }
+    }
+
+    fn value_text(&self, settings: &GameSettings, localization: &Localization) -> String {
+        let toggle = |on: bool| localization.get(if on { "settings.on" } else { "settings.off" });
+        match self {
+            SettingKind::MasterVolume => format!("{:.0}%", settings.master_volume * 100.0),
+            SettingKind::MusicVolume => format!("{:.0}%", settings.music_volume * 100.0),
+            SettingKind::SfxVolume => format!("{:.0}%", settings.sfx_volume * 100.0),
+            SettingKind::Fullscreen => toggle(settings.fullscreen),
+            SettingKind::Resolution => {
+                format!("{}x{}", settings.resolution.x, settings.resolution.y)
+            }
+            SettingKind::Vsync => toggle(settings.vsync),
+            SettingKind::TextSpeed => format!("{:.0}", settings.text_speed),
+            SettingKind::UiScale => format!("{:.1}x", settings.ui_scale),
+            SettingKind::Language => settings.locale.display_name().to_string(),
+        }
+    }
+
+    fn step(&self, settings: &mut GameSettings, direction: f32) {
+        let step_volume = |volume: &mut f32| {
+            *volume = (*volume + VOLUME_STEP * direction)
+                .clamp(*VOLUME_RANGE.start(), *VOLUME_RANGE.end());
+        };
+        match self {
+            SettingKind::MasterVolume => step_volume(&mut settings.master_volume),
+            SettingKind::MusicVolume => step_volume(&mut settings.music_volume),
+            SettingKind::SfxVolume => step_volume(&mut settings.sfx_volume),
+            SettingKind::Fullscreen => settings.fullscreen = !settings.fullscreen,
+            SettingKind::Resolution => {
+                let index = RESOLUTIONS
+                    .iter()
+                    .position(|resolution| *resolution == settings.resolution)
+                    .unwrap_or(0);
+                let next = (index as i32 + direction as i32).rem_euclid(RESOLUTIONS.len() as i32);
+                settings.resolution = RESOLUTIONS[next as usize];
+            }
+            SettingKind::Vsync => settings.vsync = !settings.vsync,
+            SettingKind::TextSpeed => {
+                settings.text_speed = (settings.text_speed + TEXT_SPEED_STEP * direction)
+                    .clamp(*TEXT_SPEED_RANGE.start(), *TEXT_SPEED_RANGE.end());
+            }
+            SettingKind::UiScale => {
+                settings.ui_scale = (settings.ui_scale + UI_SCALE_STEP * direction)
+                    .clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end());
+            }
+            SettingKind::Language => settings.locale = settings.locale.next(),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct OnSettingsScreen;
+
+#[derive(Component)]
+pub struct SettingStepButton {
+    pub kind: SettingKind,
+    pub direction: f32,
+}
+
+#[derive(Component)]
+pub struct SettingsBackButton;
+
+#[derive(Component)]
+pub struct SettingValueText(pub SettingKind);
+
+pub fn setup_settings_menu(
+    mut commands: Commands,
+    localization: Res<Localization>,
+    settings: Res<GameSettings>,
+    theme: Res<UiTheme>,
+) {
+    commands
+        .spawn((theme.backdrop(20), OnSettingsScreen))
+        .with_children(|parent| {
+            parent.spawn((
+                theme.label(localization.get("settings.title"), LabelSize::Title),
+                LocalizedText("settings.title"),
+            ));
+            for kind in SettingKind::ALL {
+                parent
+                    .spawn(Node {
+                        width: Val::Px(640.0),
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(12.0),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Text::new(localization.get(kind.label_key())),
+                            theme.text_font(LabelSize::Body),
+                            TextColor(theme.text_light),
+                            Node {
+                                flex_grow: 1.0,
+                                ..default()
+                            },
+                            LocalizedText(kind.label_key()),
+                        ));
+                        parent.spawn((
+                            theme.labelled_button(ButtonSize::Small, "<"),
+                            SettingStepButton {
+                                kind,
+                                direction: -1.0,
+                            },
+                        ));
+                        parent.spawn((
+                            theme.label(kind.value_text(&settings, &localization), LabelSize::Body),
+                            TextLayout::new_with_justify(JustifyText::Center),
+                            Node {
+                                width: Val::Px(160.0),
+                                ..default()
+                            },
+                            SettingValueText(kind),
+                        ));
+                        parent.spawn((
+                            theme.labelled_button(ButtonSize::Small, ">"),
+                            SettingStepButton {
+                                kind,
+                                direction: 1.0,
+                            },
+                        ));
+                    });
+            }
+            parent
+                .spawn(Node {
+                    margin: UiRect::top(Val::Px(20.0)),
+                    ..default()
+                })
+                .with_child((
+                    theme.localized_button(ButtonSize::Large, "settings.back", &localization),
+                    SettingsBackButton,
+                ));
+        });
+}
+
+pub fn settings_button_interaction_system(
+    step_buttons: PressedButtons<SettingStepButton>,
+    back_buttons: PressedButtons<SettingsBackButton>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<GameSettings>,
+    mut settings_state: ResMut<NextState<SettingsMenuState>>,
+) {
+    if keys.just_pressed(KeyCode::Escape) || back_buttons.iter().next().is_some() {
+        settings_state.set(SettingsMenuState::Closed);
+    }
+    for step_button in step_buttons.iter() {
+        step_button.kind.step(&mut settings, step_button.direction);
+    }
+}
+
+pub fn update_settings_text(
+    settings: Res<GameSettings>,
+    localization: Res<Localization>,
+    mut value_query: Query<(&mut Text, &SettingValueText)>,
+) {
+    if !settings.is_changed() && !localization.is_changed() {
+        return;
+    }
+    for (mut text, value) in value_query.iter_mut() {
+        text.0 = value.0.value_text(&settings, &localization);
+    }
+}
+
+pub fn close_settings_menu(
+    mut commands: Commands,
+    query: Query<Entity, With<OnSettingsScreen>>,
+    settings: Res<GameSettings>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+    settings.save();
+}
@@ -0,0 +1,248 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    bar::{
+        crafting::{CraftingEvent, OnCraftingScreen},
+        glass::Glass,
+        technique::{PreparedTechnique, Technique, round_score},
+    },
+    localization::Localization,
+    ui::{
+        crafting_ui::CraftingButtons,
+        widgets::{Disabled, LabelSize, PressedButtons, UiTheme},
+    },
+};
+
+const TRACK_WIDTH: f32 = 480.0;
+const TRACK_HEIGHT: f32 = 28.0;
+const ZONE_COLOR: Color = Color::srgba(0.2, 0.9, 0.5, 0.6);
+
+#[derive(Component)]
+pub struct TechniqueButton(pub Technique);
+
+/// The running timing mini-game; lives on its overlay.
+#[derive(Component)]
+pub struct TechniqueGame {
+    pub technique: Technique,
+    /// Marker position along the bar, 0 to 1.
+    pub marker: f32,
+    pub forward: bool,
+    pub scores: Vec<f32>,
+}
+
+#[derive(Component)]
+pub struct TechniqueMarker;
+
+#[derive(Component)]
+pub struct TechniqueZone;
+
+#[derive(Component)]
+pub struct TechniqueRoundText;
+
+pub fn technique_button_system(
+    mut commands: Commands,
+    pressed: PressedButtons<TechniqueButton>,
+    game_query: Query<(), With<TechniqueGame>>,
+    glass_query: Query<&Glass>,
+    mut crafting_events: EventWriter<CraftingEvent>,
+    theme: Res<UiTheme>,
+    localization: Res<Localization>,
+) {
+    let Some(TechniqueButton(technique)) = pressed.iter().next() else {
+        return;
+    };
+    let has_ingredients = glass_query
+        .iter()
+        .any(|glass| glass.get_current_volume() > 0.0);
+    if !game_query.is_empty() || !has_ingredients {
+        return;
+    }
+    info!("Preparing drink with {:?}", technique);
+    crafting_events.write(CraftingEvent::Preparing(*technique));
+    spawn_technique_game(&mut commands, *technique, &theme, &localization);
+}
+
+fn spawn_technique_game(
+    commands: &mut Commands,
+    technique: Technique,
+    theme: &UiTheme,
+    localization: &Localization,
+) {
+    let spec = technique.spec();
+    commands
+        .spawn((
+            theme.backdrop(12),
+            TechniqueGame {
+                technique,
+                marker: 0.0,
+                forward: true,
+                scores: Vec::new(),
+            },
+            OnCraftingScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn(theme.label(localization.get(technique.label_key()), LabelSize::Title));
+            parent.spawn((
+                theme.label(
+                    round_text(localization, 0, spec.targets.len()),
+                    LabelSize::Body,
+                ),
+                TechniqueRoundText,
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(TRACK_WIDTH),
+                        height: Val::Px(TRACK_HEIGHT),
+                        border: UiRect::all(Val::Px(3.0)),
+                        ..default()
+                    },
+                    BorderColor(theme.border),
+                    BorderRadius::all(Val::Px(6.0)),
+                    BackgroundColor(theme.button_normal),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Percent((spec.targets[0] - spec.zone_width / 2.0) * 100.0),
+                            width: Val::Percent(spec.zone_width * 100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(ZONE_COLOR),
+                        TechniqueZone,
+                    ));
+                    parent.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Percent(0.0),
+                            width: Val::Px(4.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(theme.text_light),
+                        TechniqueMarker,
+                    ));
+                });
+            parent.spawn(theme.label(localization.get("technique.prompt"), LabelSize::Small));
+        });
+}
+
+fn round_text(localization: &Localization, round: usize, rounds: usize) -> String {
+    format!(
+        "{} {}/{}",
+        localization.get("technique.round"),
+        round + 1,
+        rounds
+    )
+}
+
+/// Space, Enter, a click or the gamepad's south button stop the marker.
+#[derive(SystemParam)]
+pub struct StopInput<'w, 's> {
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    mouse: Res<'w, ButtonInput<MouseButton>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl StopInput<'_, '_> {
+    fn just_pressed(&self) -> bool {
+        self.keys.just_pressed(KeyCode::Space)
+            || self.keys.just_pressed(KeyCode::Enter)
+            || self.mouse.just_pressed(MouseButton::Left)
+            || self
+                .gamepads
+                .iter()
+                .any(|gamepad| gamepad.just_pressed(GamepadButton::South))
+    }
+}
+
+/// The moving parts of the mini-game overlay.
+#[derive(SystemParam)]
+pub struct TechniqueTrack<'w, 's> {
+    marker_query: Query<'w, 's, &'static mut Node, (With<TechniqueMarker>, Without<TechniqueZone>)>,
+    zone_query: Query<'w, 's, &'static mut Node, (With<TechniqueZone>, Without<TechniqueMarker>)>,
+    round_text_query: Query<'w, 's, &'static mut Text, With<TechniqueRoundText>>,
+}
+
+/// Sweeps the marker and scores each stop; the last stop stores the result on the glass.
+pub fn run_technique_game(
+    mut commands: Commands,
+    time: Res<Time>,
+    stop: StopInput,
+    mut game_query: Query<(Entity, &mut TechniqueGame)>,
+    mut track: TechniqueTrack,
+    mut glass_query: Query<&mut Glass>,
+    localization: Res<Localization>,
+) {
+    let Ok((entity, mut game)) = game_query.single_mut() else {
+        return;
+    };
+    let spec = game.technique.spec();
+    let step = spec.speed * time.delta_secs();
+    game.marker += if game.forward { step } else { -step };
+    if game.marker >= 1.0 {
+        game.marker = 1.0;
+        game.forward = false;
+    } else if game.marker <= 0.0 {
+        game.marker = 0.0;
+        game.forward = true;
+    }
+    for mut node in track.marker_query.iter_mut() {
+        node.left = Val::Percent(game.marker * 100.0);
+    }
+
+    if !stop.just_pressed() {
+        return;
+    }
+    let round = game.scores.len();
+    let score = round_score(game.marker, spec.targets[round], spec.zone_width);
+    game.scores.push(score);
+    info!(
+        "{:?} round {} scored {:.2}",
+        game.technique,
+        round + 1,
+        score
+    );
+
+    if let Some(next_target) = spec.targets.get(round + 1) {
+        for mut node in track.zone_query.iter_mut() {
+            node.left = Val::Percent((next_target - spec.zone_width / 2.0) * 100.0);
+        }
+        for mut text in track.round_text_query.iter_mut() {
+            text.0 = round_text(&localization, round + 1, spec.targets.len());
+        }
+        return;
+    }
+
+    let prepared = PreparedTechnique {
+        technique: game.technique,
+        quality: game.scores.iter().sum::<f32>() / game.scores.len() as f32,
+    };
+    info!("Prepared {:?}", prepared);
+    for mut glass in glass_query.iter_mut() {
+        glass.technique = Some(prepared);
+    }
+    commands.entity(entity).despawn();
+}
+
+/// Craft needs a prepared glass, and there is nothing to prepare in an empty one.
+pub fn update_crafting_availability(
+    glass_query: Query<&Glass, Changed<Glass>>,
+    mut craft_buttons: Query<(&CraftingButtons, &mut Disabled)>,
+    mut technique_buttons: Query<&mut Disabled, (With<TechniqueButton>, Without<CraftingButtons>)>,
+) {
+    let Some(glass) = glass_query.iter().next() else {
+        return;
+    };
+    for (button, mut disabled) in craft_buttons.iter_mut() {
+        if matches!(button, CraftingButtons::Craft) {
+            disabled.set_if_neq(Disabled(glass.technique.is_none()));
+        }
+    }
+    let empty = glass.get_current_volume() <= 0.0;
+    for mut disabled in technique_buttons.iter_mut() {
+        disabled.set_if_neq(Disabled(empty));
+    }
+}
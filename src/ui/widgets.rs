@@ -0,0 +1,328 @@
+use bevy::{ecs::system::SystemParam, prelude::*, ui::FocusPolicy};
+
+use crate::{
+    animation::tween::{Ease, Tween, TweenTarget},
+    constants::{
+        BUTTON_BORDER, DISABLED_BUTTON, DISABLED_TEXT_COLOR, HOVERED_BUTTON, NORMAL_BUTTON,
+        PRESSED_BUTTON, TEXT_COLOR, WHITE,
+    },
+    localization::{Localization, LocalizedText},
+};
+
+const TOAST_SECONDS: f32 = 2.0;
+const TOAST_FADE_SECONDS: f32 = 0.2;
+
+pub struct WidgetsPlugin;
+
+impl Plugin for WidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiTheme>()
+            .add_systems(Update, update_button_colors);
+    }
+}
+
+/// Colours, font and sizes shared by every screen.
+#[derive(Resource, Clone)]
+pub struct UiTheme {
+    pub font: Handle<Font>,
+    pub text: Color,
+    pub text_light: Color,
+    pub text_disabled: Color,
+    pub button_normal: Color,
+    pub button_hovered: Color,
+    pub button_pressed: Color,
+    pub button_disabled: Color,
+    pub border: Color,
+    pub panel_background: Color,
+    pub backdrop: Color,
+    pub toast_background: Color,
+    pub toast_border: Color,
+}
+
+impl FromWorld for UiTheme {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            font: world.resource::<AssetServer>().load("fonts/Nasa21.ttf"),
+            text: TEXT_COLOR,
+            text_light: WHITE,
+            text_disabled: DISABLED_TEXT_COLOR,
+            button_normal: NORMAL_BUTTON,
+            button_hovered: HOVERED_BUTTON,
+            button_pressed: PRESSED_BUTTON,
+            button_disabled: DISABLED_BUTTON,
+            border: BUTTON_BORDER,
+            panel_background: NORMAL_BUTTON.with_alpha(0.95),
+            backdrop: Color::srgba(0.0, 0.0, 0.0, 0.8),
+            toast_background: Color::srgb(0.8, 0.1, 0.1),
+            toast_border: Color::srgb(1.0, 0.0, 0.0),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ButtonSize {
+    /// Main and pause menu entries.
+    Large,
+    /// Crafting controls and the dialogue log toggle.
+    Medium,
+    /// Square arrow buttons such as the settings steppers.
+    Small,
+    /// Sized by its label, used for dialogue options.
+    Inline,
+}
+
+impl ButtonSize {
+    fn node(&self) -> Node {
+        let (width, height) = match self {
+            ButtonSize::Large => (Val::Px(250.0), Val::Px(65.0)),
+            ButtonSize::Medium => (Val::Px(150.0), Val::Px(50.0)),
+            ButtonSize::Small => (Val::Px(50.0), Val::Px(50.0)),
+            ButtonSize::Inline => (Val::Auto, Val::Auto),
+        };
+        let (border, padding) = match self {
+            ButtonSize::Inline => (3.0, UiRect::axes(Val::Px(16.0), Val::Px(8.0))),
+            _ => (5.0, UiRect::ZERO),
+        };
+        Node {
+            width,
+            height,
+            border: UiRect::all(Val::Px(border)),
+            padding,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        }
+    }
+
+    fn radius(&self) -> BorderRadius {
+        match self {
+            ButtonSize::Inline => BorderRadius::all(Val::Px(8.0)),
+            _ => BorderRadius::MAX,
+        }
+    }
+
+    fn label_size(&self) -> LabelSize {
+        match self {
+            ButtonSize::Large => LabelSize::Large,
+            ButtonSize::Medium | ButtonSize::Small => LabelSize::Body,
+            ButtonSize::Inline => LabelSize::Small,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LabelSize {
+    Title,
+    Large,
+    Body,
+    Small,
+}
+
+impl LabelSize {
+    pub fn font_size(&self) -> f32 {
+        match self {
+            LabelSize::Title => 40.0,
+            LabelSize::Large => 30.0,
+            LabelSize::Body => 24.0,
+            LabelSize::Small => 20.0,
+        }
+    }
+}
+
+/// Marks buttons whose colours follow the theme.
+#[derive(Component)]
+pub struct ThemedButton;
+
+/// A disabled button keeps its place in the layout but ignores presses.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Disabled(pub bool);
+
+/// Short message that fades in, then fades out and removes itself after a couple of seconds.
+#[derive(Component)]
+pub struct Toast;
+
+impl UiTheme {
+    pub fn text_font(&self, size: LabelSize) -> TextFont {
+        TextFont {
+            font: self.font.clone(),
+            font_size: size.font_size(),
+            ..default()
+        }
+    }
+
+    pub fn label(&self, text: impl Into<String>, size: LabelSize) -> impl Bundle {
+        (Text::new(text), self.text_font(size), TextColor(self.text))
+    }
+
+    /// A button without a label, for callers that need to mark the label.
+    pub fn button(&self, size: ButtonSize) -> impl Bundle {
+        (
+            Button,
+            ThemedButton,
+            Disabled::default(),
+            size.node(),
+            BorderColor(self.border),
+            size.radius(),
+            BackgroundColor(self.button_normal),
+        )
+    }
+
+    pub fn labelled_button(&self, size: ButtonSize, text: impl Into<String>) -> impl Bundle {
+        (
+            self.button(size),
+            children![self.label(text, size.label_size())],
+        )
+    }
+
+    /// A labelled button showing a string table entry in the current language.
+    pub fn localized_button(
+        &self,
+        size: ButtonSize,
+        key: &'static str,
+        localization: &Localization,
+    ) -> impl Bundle {
+        (
+            self.button(size),
+            children![(
+                self.label(localization.get(key), size.label_size()),
+                LocalizedText(key)
+            )],
+        )
+    }
+
+    /// Bordered box; `node` supplies the layout, the theme the padding and border.
+    pub fn panel(&self, node: Node) -> impl Bundle {
+        (
+            Node {
+                padding: UiRect::all(Val::Px(16.0)),
+                border: UiRect::all(Val::Px(5.0)),
+                ..node
+            },
+            BorderColor(self.border),
+            BorderRadius::all(Val::Px(12.0)),
+            BackgroundColor(self.panel_background),
+        )
+    }
+
+    /// Full-screen dimmed layer that centres its children and blocks the screen below.
+    pub fn backdrop(&self, z_index: i32) -> impl Bundle {
+        (
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(self.backdrop),
+            FocusPolicy::Block,
+            GlobalZIndex(z_index),
+        )
+    }
+
+    /// Small colour-coded tag, e.g. an ingredient's taste.
+    pub fn chip(&self, text: impl Into<String>, color: Color) -> impl Bundle {
+        (
+            Node {
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(6.0)),
+            BackgroundColor(color),
+            children![(
+                Text::new(text),
+                self.text_font(LabelSize::Small),
+                TextColor(self.text_light),
+            )],
+        )
+    }
+
+    pub fn toast(&self, text: impl Into<String>) -> impl Bundle {
+        // Spawned transparent; the tween fades it in.
+        (
+            Text::new(text),
+            self.text_font(LabelSize::Large),
+            TextColor(self.text_light.with_alpha(0.0)),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                left: Val::Px(20.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BorderColor(self.toast_border.with_alpha(0.0)),
+            BorderRadius::all(Val::Px(8.0)),
+            BackgroundColor(self.toast_background.with_alpha(0.0)),
+            GlobalZIndex(30),
+            Toast,
+            Tween::new(
+                TweenTarget::Alpha { from: 0.0, to: 1.0 },
+                TOAST_FADE_SECONDS,
+                Ease::QuadOut,
+            )
+            .then_wait(TOAST_SECONDS - 2.0 * TOAST_FADE_SECONDS)
+            .then(
+                TweenTarget::Alpha { from: 1.0, to: 0.0 },
+                TOAST_FADE_SECONDS,
+                Ease::QuadIn,
+            )
+            .despawn_on_finish(),
+        )
+    }
+}
+
+type InteractionChanged = (Changed<Interaction>, With<Button>);
+
+/// Enabled buttons of one kind that were pressed this frame, by mouse or navigation.
+#[derive(SystemParam)]
+pub struct PressedButtons<'w, 's, T: Component> {
+    query: Query<'w, 's, (&'static Interaction, &'static Disabled, &'static T), InteractionChanged>,
+}
+
+impl<T: Component> PressedButtons<'_, '_, T> {
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.query
+            .iter()
+            .filter(|(interaction, disabled, _)| {
+                **interaction == Interaction::Pressed && !disabled.0
+            })
+            .map(|(_, _, button)| button)
+    }
+}
+
+type ButtonStateChanged = (
+    With<ThemedButton>,
+    Or<(Changed<Interaction>, Changed<Disabled>)>,
+);
+
+fn update_button_colors(
+    theme: Res<UiTheme>,
+    mut buttons: Query<
+        (&Interaction, &Disabled, &mut BackgroundColor, &Children),
+        ButtonStateChanged,
+    >,
+    mut labels: Query<&mut TextColor>,
+) {
+    for (interaction, disabled, mut background, children) in buttons.iter_mut() {
+        background.0 = match (*interaction, disabled.0) {
+            (_, true) => theme.button_disabled,
+            (Interaction::Pressed, false) => theme.button_pressed,
+            (Interaction::Hovered, false) => theme.button_hovered,
+            (Interaction::None, false) => theme.button_normal,
+        };
+        let text_color = if disabled.0 {
+            theme.text_disabled
+        } else {
+            theme.text
+        };
+        for child in children.iter() {
+            if let Ok(mut color) = labels.get_mut(child) {
+                color.0 = text_color;
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::{fs, path::Path};
+
+#[path = "../src/engine/asset_manifest.rs"]
+mod asset_manifest;
+
+use asset_manifest::{check, manifest_paths};
+
+fn assets_dir() -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("assets")
+}
+
+#[test]
+fn manifest_files_exist_with_exact_case() {
+    let paths = manifest_paths();
+    assert!(
+        paths.iter().any(|path| path.starts_with("images/")),
+        "no #[asset(path)] attributes found"
+    );
+    let report = check(&assets_dir(), &paths).expect("assets folder is readable");
+    for path in report.unused.iter() {
+        eprintln!("unused asset: {path}");
+    }
+    assert!(report.missing.is_empty(), "missing: {:?}", report.missing);
+    assert!(
+        report.wrong_case.is_empty(),
+        "wrong case (requested, on disk): {:?}",
+        report.wrong_case
+    );
+}
+
+/// Paths passed straight to the asset server must be in the manifest too.
+#[test]
+fn direct_loads_are_in_the_manifest() {
+    fn visit(dir: &Path, found: &mut Vec<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                visit(&path, found);
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                let source = fs::read_to_string(&path).unwrap();
+                for call in [".load(\"", "YarnFileSource::file(\""] {
+                    for (index, _) in source.match_indices(call) {
+                        let rest = &source[index + call.len()..];
+                        found.push(rest[..rest.find('"').unwrap()].to_string());
+                    }
+                }
+            }
+        }
+    }
+    let mut found = Vec::new();
+    visit(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        &mut found,
+    );
+    let paths = manifest_paths();
+    let unlisted: Vec<&String> = found
+        .iter()
+        .filter(|path| !paths.contains(&path.as_str()))
+        .collect();
+    assert!(unlisted.is_empty(), "add to DIRECT_LOADS: {unlisted:?}");
+}
+
+#[test]
+fn reports_wrong_case_missing_and_unused() {
+    let dir = std::env::temp_dir().join(format!("asset_manifest_{}", std::process::id()));
+    fs::create_dir_all(dir.join("images")).unwrap();
+    fs::write(dir.join("images/Sweetflux.png"), []).unwrap();
+    fs::write(dir.join("images/spare.png"), []).unwrap();
+    let report = check(&dir, &["images/sweetflux.png", "images/gone.png"]).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(
+        report.wrong_case,
+        vec![(
+            "images/sweetflux.png".to_string(),
+            "images/Sweetflux.png".to_string()
+        )]
+    );
+    assert_eq!(report.missing, vec!["images/gone.png".to_string()]);
+    assert_eq!(report.unused, vec!["images/spare.png".to_string()]);
+}
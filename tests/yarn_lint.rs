@@ -0,0 +1,102 @@
+use std::path::Path;
+
+#[path = "../src/dialogues/yarn_lint.rs"]
+mod yarn_lint;
+
+use yarn_lint::{Diagnostic, lint, read_yarn_files};
+
+fn lint_source(source: &str) -> Vec<String> {
+    lint(&[("test.yarn".to_string(), source.to_string())])
+        .iter()
+        .map(|diagnostic: &Diagnostic| diagnostic.message.clone())
+        .collect()
+}
+
+/// Declarations and start nodes every valid project needs.
+const PROJECT: &str = "\
+title: BartenderMonologue
+---
+<<declare $calming_effect = 0 as number>>
+<<declare $energizing_effect = 0 as number>>
+<<declare $mind_enhancing_effect = 0 as number>>
+<<declare $courage_effect = 0 as number>>
+<<declare $truth_effect = 0 as number>>
+<<declare $healing_effect = 0 as number>>
+<<declare $drink_stars = 0 as number>>
+<<declare $night = 1 as number>>
+===
+title: CarlEnters
+---
+===
+title: ZaraEnters
+---
+===
+title: CodaEnters
+---
+===
+title: MysteryEnters
+---
+===
+";
+
+#[test]
+fn dialogue_scripts_pass() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/dialogue");
+    let files = read_yarn_files(&dir).expect("dialogue folder is readable");
+    assert!(!files.is_empty(), "no Yarn files in {}", dir.display());
+    let diagnostics: Vec<String> = lint(&files).iter().map(ToString::to_string).collect();
+    assert!(diagnostics.is_empty(), "{}", diagnostics.join("\n"));
+}
+
+#[test]
+fn minimal_project_passes() {
+    assert_eq!(lint_source(PROJECT), Vec::<String>::new());
+}
+
+#[test]
+fn reports_misspelled_command_arguments() {
+    let source = format!(
+        "{PROJECT}title: Typos\n---\n<<change_dialog_state \"Zaraa\">>\n<<change_gamestate \"Craftng\">>\n<<consume_drink now>>\n==="
+    );
+    let messages = lint_source(&source);
+    assert_eq!(messages.len(), 3, "{messages:?}");
+    assert!(messages[0].contains("\"Zaraa\""));
+    assert!(messages[1].contains("\"Craftng\""));
+    assert!(messages[2].contains("takes 0 argument(s)"));
+}
+
+#[test]
+fn reports_unknown_commands_and_jump_targets() {
+    let source = format!("{PROJECT}title: Broken\n---\n<<jump Nowhere>>\n<<dance>>\n===");
+    let messages = lint_source(&source);
+    assert!(
+        messages
+            .iter()
+            .any(|message| message == "jump target Nowhere does not exist")
+    );
+    assert!(
+        messages
+            .iter()
+            .any(|message| message == "<<dance>> is not a registered command")
+    );
+}
+
+#[test]
+fn reports_undeclared_variables() {
+    let source = "title: BartenderMonologue\n---\n<<if $truth_efect >= 6>>\n<<endif>>\n===";
+    let messages = lint_source(source);
+    assert!(messages.contains(&"$truth_efect is never declared or set".to_string()));
+    assert!(messages.contains(&"$drink_stars is set by the game but never declared".to_string()));
+}
+
+#[test]
+fn reports_compiler_errors_with_their_file() {
+    let source = format!("{PROJECT}title: Unclosed\n---\n<<if $night > 1>>\nStill open.\n===");
+    let diagnostics = lint(&[("test.yarn".to_string(), source)]);
+    assert!(!diagnostics.is_empty());
+    assert!(
+        diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.file == "test.yarn")
+    );
+}